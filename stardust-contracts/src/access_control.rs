@@ -0,0 +1,108 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+/// Access Control Module for StardustEngine
+/// Role-based permission checks for privileged game operations
+#[multiversx_sc::module]
+pub trait AccessControl {
+    
+    /// Grants a role to an address. Only admins can manage roles.
+    #[endpoint]
+    fn grant_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_is_admin();
+        require!(self.role_members(&role).insert(address.clone()), "Role already granted");
+        
+        self.role_granted_event(&role, &address, &self.blockchain().get_caller());
+    }
+    
+    /// Revokes a role from an address. The contract owner stays admin regardless.
+    #[endpoint]
+    fn revoke_role(&self, role: Role, address: ManagedAddress) {
+        self.require_caller_is_admin();
+        require!(self.role_members(&role).swap_remove(&address), "Role not granted");
+        
+        self.role_revoked_event(&role, &address, &self.blockchain().get_caller());
+    }
+    
+    fn require_caller_is_admin(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.is_admin(&caller), "Only admin can call this function");
+    }
+    
+    fn require_caller_has_role(&self, role: Role) {
+        let caller = self.blockchain().get_caller();
+        require!(self.has_role(&role, &caller), "Caller does not have the required role");
+    }
+    
+    fn is_admin(&self, address: &ManagedAddress) -> bool {
+        *address == self.blockchain().get_owner_address() || self.role_members(&Role::Admin).contains(address)
+    }
+    
+    /// Admins implicitly hold every role.
+    fn has_role(&self, role: &Role, address: &ManagedAddress) -> bool {
+        self.is_admin(address) || self.role_members(role).contains(address)
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_role_members(&self, role: Role) -> MultiValueEncoded<ManagedAddress> {
+        self.role_members(&role).iter().collect()
+    }
+    
+    #[view]
+    fn get_roles(&self, address: ManagedAddress) -> MultiValueEncoded<Role> {
+        let mut roles = MultiValueEncoded::new();
+        for role in Role::ALL.iter() {
+            if self.has_role(role, &address) {
+                roles.push(role.clone());
+            }
+        }
+        roles
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("roleMembers")]
+    fn role_members(&self, role: &Role) -> UnorderedSetMapper<ManagedAddress>;
+    
+    // ===== EVENTS =====
+    
+    #[event("roleGranted")]
+    fn role_granted_event(
+        &self,
+        #[indexed] role: &Role,
+        #[indexed] address: &ManagedAddress,
+        granted_by: &ManagedAddress,
+    );
+    
+    #[event("roleRevoked")]
+    fn role_revoked_event(
+        &self,
+        #[indexed] role: &Role,
+        #[indexed] address: &ManagedAddress,
+        revoked_by: &ManagedAddress,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum Role {
+    Admin,
+    GameMaster,
+    QuestDesigner,
+    TournamentOperator,
+    Treasurer,
+}
+
+impl Role {
+    pub const ALL: [Role; 5] = [
+        Role::Admin,
+        Role::GameMaster,
+        Role::QuestDesigner,
+        Role::TournamentOperator,
+        Role::Treasurer,
+    ];
+}
@@ -0,0 +1,88 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+use crate::asset_storage::{AssetType, Rarity};
+
+/// Rarity multipliers in tenths, applied to the template stats of a newly minted asset
+pub const RARITY_MULTIPLIERS: [u32; 4] = [10, 25, 50, 100];
+
+const RARITY_MULTIPLIER_DENOMINATOR: u32 = 10;
+
+/// Asset Classes Module for StardustEngine
+/// Stat templates per asset type, scaled by rarity when an asset is minted
+#[multiversx_sc::module]
+pub trait AssetClasses: access_control::AccessControl {
+    
+    /// Sets the common rarity stat template of an asset type. Already minted assets keep their stats.
+    #[endpoint]
+    fn set_asset_type_stats(&self, asset_type: AssetType, stats: AssetStats) {
+        self.require_caller_has_role(Role::GameMaster);
+        
+        self.asset_type_stats(&asset_type).set(&stats);
+        self.asset_type_stats_set_event(&asset_type, &stats);
+    }
+    
+    /// Template stats of the asset type multiplied by the rarity multiplier.
+    fn calculate_asset_stats(&self, asset_type: &AssetType, rarity: &Rarity) -> AssetStats {
+        let template = self.get_asset_type_stats(asset_type.clone());
+        let multiplier = RARITY_MULTIPLIERS[rarity.clone() as usize];
+        let scale = |stat: u32| stat * multiplier / RARITY_MULTIPLIER_DENOMINATOR;
+        
+        AssetStats {
+            attack: scale(template.attack),
+            defense: scale(template.defense),
+            speed: scale(template.speed),
+            special: scale(template.special),
+        }
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    /// Falls back to the default template of the asset type while none is configured.
+    #[view]
+    fn get_asset_type_stats(&self, asset_type: AssetType) -> AssetStats {
+        let stats_mapper = self.asset_type_stats(&asset_type);
+        if !stats_mapper.is_empty() {
+            return stats_mapper.get();
+        }
+        
+        let (attack, defense, speed, special) = match asset_type {
+            AssetType::Weapon => (10, 10, 0, 0),
+            AssetType::Character => (8, 14, 5, 5),
+            AssetType::Vehicle => (12, 12, 10, 0),
+            AssetType::Consumable => (0, 0, 0, 20),
+            AssetType::Skin => (0, 0, 0, 0),
+            AssetType::Structure => (0, 20, 0, 0),
+        };
+        AssetStats {
+            attack,
+            defense,
+            speed,
+            special,
+        }
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("assetTypeStats")]
+    fn asset_type_stats(&self, asset_type: &AssetType) -> SingleValueMapper<AssetStats>;
+    
+    // ===== EVENTS =====
+    
+    #[event("assetTypeStatsSet")]
+    fn asset_type_stats_set_event(&self, #[indexed] asset_type: &AssetType, stats: &AssetStats);
+}
+
+// ===== DATA STRUCTURES =====
+
+/// Attack and defense drive combat damage and HP, speed adds dodge chance,
+/// special adds Special move damage or, for consumables, the HP they restore.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
+pub struct AssetStats {
+    pub attack: u32,
+    pub defense: u32,
+    pub speed: u32,
+    pub special: u32,
+}
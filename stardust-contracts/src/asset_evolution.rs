@@ -0,0 +1,242 @@
+use multiversx_sc::imports::*;
+
+use crate::access_control::{self, Role};
+use crate::asset_storage::{self, AssetType, Rarity};
+use crate::pause::{self, Subsystem};
+use crate::stardust_token::StardustFlow;
+use crate::treasury::{self, RevenueSource};
+use crate::{asset_classes, level_calculation, player_storage, stardust_token};
+
+/// Stardust Points an upgrade costs per current asset level, used until a game master configures a cost
+pub const DEFAULT_UPGRADE_POINTS_PER_LEVEL: u64 = 50;
+
+/// Highest level upgrades can raise an asset to
+pub const MAX_UPGRADE_LEVEL: u32 = 50;
+
+/// Level a Common, Rare or Epic asset needs to evolve to the next rarity
+pub const EVOLUTION_LEVELS: [u32; 3] = [5, 10, 20];
+
+/// Assets of the same type and rarity burned by one fusion
+pub const FUSION_ASSET_COUNT: usize = 3;
+
+/// Asset Evolution Module for StardustEngine
+/// Level upgrades, rarity evolution and fusion of game assets
+#[multiversx_sc::module]
+pub trait AssetEvolution:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + level_calculation::LevelCalculation
+    + pause::Pause
+    + treasury::Treasury
+    + stardust_token::StardustToken
+{
+    
+    /// Raises the level of the asset NFT sent along with the call by one and returns the asset.
+    /// Paid in Stardust tokens or the upgrade token when a payment is sent along, otherwise
+    /// from the stored Stardust Points.
+    #[endpoint]
+    #[payable("*")]
+    fn upgrade_asset(&self) -> u32 {
+        self.require_not_paused(Subsystem::Minting);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        let (assets, payment) = self.receive_asset_payments(&caller);
+        require!(assets.len() == 1, "Exactly one asset required");
+        
+        let asset_id = assets.get(0);
+        let mut asset = self.asset_metadata(asset_id).get();
+        require!(asset.level < MAX_UPGRADE_LEVEL, "Asset at max level");
+        
+        match payment {
+            Some(payment) if !self.is_stardust_payment(&payment) => {
+                require!(!self.upgrade_token_price().is_empty(), "Upgrades not sold for tokens");
+                let price = self.upgrade_token_price().get();
+                require!(
+                    payment.token_identifier == price.token_identifier && payment.amount == price.amount * asset.level,
+                    "Wrong upgrade payment"
+                );
+                self.record_revenue(RevenueSource::AssetUpgrade, &payment.token_identifier, &payment.amount);
+            },
+            stardust_payment => {
+                let cost = self.get_upgrade_points_cost(asset_id);
+                self.spend_stardust(&caller, cost, stardust_payment, StardustFlow::AssetUpgrade);
+            },
+        }
+        
+        asset.level += 1;
+        self.save_game_asset(&asset);
+        self.send_asset(&caller, asset_id);
+        
+        self.asset_upgraded_event(&caller, asset_id, asset.level);
+        self.asset_level_up_event(asset_id, asset.level - 1, asset.level);
+        asset.level
+    }
+    
+    /// Evolves the asset NFT sent along with the call to the next rarity once it reached
+    /// the evolution level. Its stats are recalculated for the new rarity.
+    #[endpoint]
+    #[payable("*")]
+    fn evolve_asset(&self) -> Rarity {
+        self.require_not_paused(Subsystem::Minting);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        let (assets, payment) = self.receive_asset_payments(&caller);
+        require!(payment.is_none(), "Evolution takes no payment");
+        require!(assets.len() == 1, "Exactly one asset required");
+        
+        let asset_id = assets.get(0);
+        let mut asset = self.asset_metadata(asset_id).get();
+        let next_rarity = match asset.rarity.next() {
+            Some(rarity) => rarity,
+            None => sc_panic!("Asset at max rarity"),
+        };
+        require!(asset.level >= EVOLUTION_LEVELS[asset.rarity.clone() as usize], "Evolution level not reached");
+        
+        asset.stats = self.calculate_asset_stats(&asset.asset_type, &next_rarity);
+        asset.rarity = next_rarity.clone();
+        self.save_game_asset(&asset);
+        self.send_asset(&caller, asset_id);
+        
+        self.asset_evolved_event(&caller, asset_id, &next_rarity);
+        next_rarity
+    }
+    
+    /// Burns the `FUSION_ASSET_COUNT` asset NFTs sent along with the call, all of the same type
+    /// and rarity, and mints one asset of that type with the next rarity.
+    #[endpoint]
+    #[payable("*")]
+    fn fuse_assets(&self, name: ManagedBuffer, description: ManagedBuffer) -> u64 {
+        self.require_not_paused(Subsystem::Minting);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        let (assets, payment) = self.receive_asset_payments(&caller);
+        require!(payment.is_none(), "Fusion takes no payment");
+        require!(assets.len() == FUSION_ASSET_COUNT, "Wrong number of assets to fuse");
+        
+        let first = self.asset_metadata(assets.get(0)).get();
+        for asset_id in assets.iter() {
+            let asset = self.asset_metadata(asset_id).get();
+            require!(
+                asset.asset_type == first.asset_type && asset.rarity == first.rarity,
+                "Fused assets must share type and rarity"
+            );
+        }
+        let next_rarity = match first.rarity.next() {
+            Some(rarity) => rarity,
+            None => sc_panic!("Asset at max rarity"),
+        };
+        
+        for asset_id in assets.iter() {
+            self.burn_game_asset(asset_id, &caller);
+        }
+        let fused_asset_id = self.create_game_asset(&caller, first.asset_type.clone(), next_rarity.clone(), name, description);
+        
+        self.assets_fused_event(&caller, fused_asset_id, &first.asset_type, &next_rarity, &assets);
+        fused_asset_id
+    }
+    
+    #[endpoint]
+    fn set_upgrade_points_cost(&self, points_per_level: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(points_per_level > 0, "Upgrade cost must be positive");
+        
+        self.upgrade_points_per_level().set(points_per_level);
+        self.upgrade_points_cost_set_event(points_per_level);
+    }
+    
+    /// Lets upgrades be bought with `amount_per_level` of the token per current asset level.
+    #[endpoint]
+    fn set_upgrade_token_price(&self, token_id: EgldOrEsdtTokenIdentifier, amount_per_level: BigUint) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(token_id.is_valid(), "Invalid payment token");
+        require!(amount_per_level > 0u32, "Upgrade cost must be positive");
+        
+        self.upgrade_token_price().set(EgldOrEsdtTokenPayment::new(token_id.clone(), 0, amount_per_level.clone()));
+        self.upgrade_token_price_set_event(&token_id, &amount_per_level);
+    }
+    
+    /// Splits the call payments into game asset NFTs and an optional fungible payment.
+    /// The player sending the assets is recorded as their owner.
+    fn receive_asset_payments(&self, player: &ManagedAddress) -> (ManagedVec<u64>, Option<EgldOrEsdtTokenPayment>) {
+        let asset_token_id = self.game_asset_token().get_token_id();
+        let mut assets = ManagedVec::new();
+        let mut payment = None;
+        
+        for transfer in self.call_value().all_transfers().iter() {
+            if transfer.token_identifier == asset_token_id {
+                self.set_asset_owner(transfer.token_nonce, player);
+                assets.push(transfer.token_nonce);
+                continue;
+            }
+            
+            require!(payment.is_none(), "Only one token payment allowed");
+            require!(transfer.token_nonce == 0, "Invalid payment token");
+            payment = Some(transfer.clone());
+        }
+        
+        (assets, payment)
+    }
+    
+    fn send_asset(&self, to: &ManagedAddress, asset_id: u64) {
+        let token_id = self.game_asset_token().get_token_id();
+        self.send().direct_esdt(to, &token_id, asset_id, &BigUint::from(1u32));
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    /// Stardust Points the next upgrade of the asset costs.
+    #[view]
+    fn get_upgrade_points_cost(&self, asset_id: u64) -> u64 {
+        require!(self.game_assets().contains(&asset_id), "Asset not found");
+        let points_per_level = if self.upgrade_points_per_level().is_empty() {
+            DEFAULT_UPGRADE_POINTS_PER_LEVEL
+        } else {
+            self.upgrade_points_per_level().get()
+        };
+        points_per_level * self.asset_metadata(asset_id).get().level as u64
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("upgradePointsPerLevel")]
+    fn upgrade_points_per_level(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("upgradeTokenPrice")]
+    fn upgrade_token_price(&self) -> SingleValueMapper<EgldOrEsdtTokenPayment>;
+    
+    // ===== EVENTS =====
+    
+    #[event("assetUpgraded")]
+    fn asset_upgraded_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] asset_id: u64,
+        new_level: u32,
+    );
+    
+    #[event("assetEvolved")]
+    fn asset_evolved_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] asset_id: u64,
+        new_rarity: &Rarity,
+    );
+    
+    #[event("assetsFused")]
+    fn assets_fused_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] fused_asset_id: u64,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] rarity: &Rarity,
+        burned_assets: &ManagedVec<u64>,
+    );
+    
+    #[event("upgradePointsCostSet")]
+    fn upgrade_points_cost_set_event(&self, points_per_level: u64);
+    
+    #[event("upgradeTokenPriceSet")]
+    fn upgrade_token_price_set_event(&self, #[indexed] token_id: &EgldOrEsdtTokenIdentifier, amount_per_level: &BigUint);
+}
@@ -0,0 +1,266 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::asset_classes::{self, AssetStats};
+use crate::{access_control, player_storage};
+
+/// Asset Storage Module for StardustEngine
+/// Game asset NFT collection, asset records, ownership records and asset locks
+#[multiversx_sc::module]
+pub trait AssetStorage:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+{
+    
+    /// Creates the NFT for a new game asset and sends it to the owner.
+    /// The asset id is the NFT nonce, the asset fields are stored as token attributes.
+    fn create_game_asset(
+        &self,
+        owner: &ManagedAddress,
+        asset_type: AssetType,
+        rarity: Rarity,
+        name: ManagedBuffer,
+        description: ManagedBuffer,
+    ) -> u64 {
+        require!(self.game_asset_token().get_token_state().is_set(), "Game asset collection not issued");
+        let token_id = self.game_asset_token().get_token_id();
+        let sc_address = self.blockchain().get_sc_address();
+        let asset_id = self.blockchain().get_current_esdt_nft_nonce(&sc_address, &token_id) + 1;
+        
+        let stats = self.calculate_asset_stats(&asset_type, &rarity);
+        let asset = GameAsset {
+            id: asset_id,
+            asset_type,
+            rarity,
+            name,
+            description,
+            created_at: self.blockchain().get_block_timestamp(),
+            level: 1u32,
+            experience: 0u64,
+            stats,
+        };
+        
+        let payment = self.game_asset_token().nft_create_and_send_named(
+            owner,
+            BigUint::from(1u32),
+            &asset.name,
+            &asset,
+        );
+        require!(payment.token_nonce == asset_id, "Unexpected asset nonce");
+        
+        self.game_assets().insert(asset_id);
+        self.asset_metadata(asset_id).set(asset);
+        self.set_asset_owner(asset_id, owner);
+        
+        asset_id
+    }
+    
+    /// Stores a changed asset and writes it to the NFT attributes.
+    /// The contract can only update the attributes while it holds the NFT.
+    fn save_game_asset(&self, asset: &GameAsset<Self::Api>) {
+        self.asset_metadata(asset.id).set(asset);
+        let token_id = self.game_asset_token().get_token_id();
+        self.send().nft_update_attributes(&token_id, asset.id, asset);
+    }
+    
+    /// Burns a game asset NFT held by the contract and forgets the asset.
+    /// `custodian` is the player who handed the asset to the contract.
+    fn burn_game_asset(&self, asset_id: u64, custodian: &ManagedAddress) {
+        self.asset_metadata(asset_id).clear();
+        let token_id = self.game_asset_token().get_token_id();
+        self.send().esdt_local_burn(&token_id, asset_id, &BigUint::from(1u32));
+        
+        self.asset_lock(asset_id).clear();
+        self.game_assets().swap_remove(&asset_id);
+        let owner = self.asset_owner(asset_id).take();
+        self.change_assets_owned(&owner, false);
+        
+        self.asset_burned_event(custodian, asset_id);
+    }
+    
+    /// Records `new_owner` as the owner of an asset and moves it between the players' `assets_owned` counts.
+    /// The owner is contract storage only, NFTs move between wallets without the contract,
+    /// so every endpoint receiving an asset records the sender as its owner first.
+    fn set_asset_owner(&self, asset_id: u64, new_owner: &ManagedAddress) {
+        let owner_mapper = self.asset_owner(asset_id);
+        if !owner_mapper.is_empty() {
+            let old_owner = owner_mapper.get();
+            if old_owner == *new_owner {
+                return;
+            }
+            self.change_assets_owned(&old_owner, false);
+        }
+        
+        owner_mapper.set(new_owner);
+        self.change_assets_owned(new_owner, true);
+    }
+    
+    fn change_assets_owned(&self, player: &ManagedAddress, increase: bool) {
+        if !self.players().contains(player) {
+            return;
+        }
+        
+        self.player_stats(player).update(|stats| {
+            if increase {
+                stats.assets_owned += 1;
+            } else {
+                stats.assets_owned = stats.assets_owned.saturating_sub(1);
+            }
+        });
+    }
+    
+    /// Marks an asset held by the contract as used by a game system until `unlock_asset`.
+    /// Only assets in custody are locked, so a locked asset cannot be sent along with another call.
+    fn lock_asset(&self, asset_id: u64, lock: AssetLock) {
+        require!(self.game_assets().contains(&asset_id), "Asset does not exist");
+        
+        self.asset_lock(asset_id).set(&lock);
+        self.asset_locked_event(asset_id, &lock);
+    }
+    
+    fn unlock_asset(&self, asset_id: u64) {
+        self.asset_lock(asset_id).clear();
+        self.asset_unlocked_event(asset_id);
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    /// Assets whose last owner recorded by the contract is `player`.
+    #[view]
+    fn get_player_assets(&self, player: ManagedAddress) -> ManagedVec<GameAsset<Self::Api>> {
+        let mut assets = ManagedVec::new();
+        for asset_id in self.game_assets().iter() {
+            if self.asset_owner(asset_id).get() == player {
+                assets.push(self.asset_metadata(asset_id).get());
+            }
+        }
+        assets
+    }
+
+    
+    #[view]
+    fn get_asset_lock(&self, asset_id: u64) -> AssetLock {
+        self.asset_lock(asset_id).get()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("gameAssets")]
+    fn game_assets(&self) -> UnorderedSetMapper<u64>;
+    
+    #[storage_mapper("assetMetadata")]
+    fn asset_metadata(&self, asset_id: u64) -> SingleValueMapper<GameAsset<Self::Api>>;
+    
+    #[storage_mapper("assetOwner")]
+    fn asset_owner(&self, asset_id: u64) -> SingleValueMapper<ManagedAddress>;
+    
+    #[storage_mapper("assetLock")]
+    fn asset_lock(&self, asset_id: u64) -> SingleValueMapper<AssetLock>;
+    
+    #[storage_mapper("gameAssetToken")]
+    fn game_asset_token(&self) -> NonFungibleTokenMapper;
+    
+    // ===== EVENTS =====
+    
+    #[event("assetMinted")]
+    fn asset_minted_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] asset_id: u64,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] rarity: &Rarity,
+        #[indexed] name: &ManagedBuffer,
+        #[indexed] payment_token: &EgldOrEsdtTokenIdentifier,
+        price: &BigUint,
+    );
+    
+    #[event("assetBurned")]
+    fn asset_burned_event(&self, #[indexed] owner: &ManagedAddress, #[indexed] asset_id: u64);
+    
+    #[event("assetLocked")]
+    fn asset_locked_event(&self, #[indexed] asset_id: u64, lock: &AssetLock);
+    
+    #[event("assetUnlocked")]
+    fn asset_unlocked_event(&self, #[indexed] asset_id: u64);
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct GameAsset<M: ManagedTypeApi> {
+    pub id: u64,
+    pub asset_type: AssetType,
+    pub rarity: Rarity,
+    pub name: ManagedBuffer<M>,
+    pub description: ManagedBuffer<M>,
+    pub created_at: u64,
+    pub level: u32,
+    pub experience: u64,
+    pub stats: AssetStats,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
+pub enum AssetType {
+    Weapon,
+    Character,
+    Skin,
+    Consumable,
+    Vehicle,
+    Structure,
+}
+
+impl AssetType {
+    pub const ALL: [AssetType; 6] = [
+        AssetType::Weapon,
+        AssetType::Character,
+        AssetType::Skin,
+        AssetType::Consumable,
+        AssetType::Vehicle,
+        AssetType::Structure,
+    ];
+    
+    /// Weapons, characters and vehicles fight, consumables are used up by moves.
+    pub fn can_fight(&self) -> bool {
+        matches!(self, AssetType::Weapon | AssetType::Character | AssetType::Vehicle)
+    }
+    
+    pub fn is_consumable(&self) -> bool {
+        *self == AssetType::Consumable
+    }
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rarity {
+    Common,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+impl Rarity {
+    pub const ALL: [Rarity; 4] = [Rarity::Common, Rarity::Rare, Rarity::Epic, Rarity::Legendary];
+    
+    /// The rarity an asset evolves or fuses into, none for Legendary.
+    pub fn next(&self) -> Option<Rarity> {
+        match self {
+            Rarity::Common => Some(Rarity::Rare),
+            Rarity::Rare => Some(Rarity::Epic),
+            Rarity::Epic => Some(Rarity::Legendary),
+            Rarity::Legendary => None,
+        }
+    }
+}
+
+/// What an asset is currently used for, see `lock_asset`. Assets without a stored lock are unlocked.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum AssetLock {
+    Unlocked,
+    InBattle,
+    InTournament,
+    Listed,
+}
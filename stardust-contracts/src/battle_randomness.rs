@@ -0,0 +1,139 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+
+/// Rolls are drawn in `0..ROLL_RANGE`, chances are expressed in basis points of it
+pub const ROLL_RANGE: u32 = 10_000;
+
+pub const DEFAULT_CRITICAL_CHANCE_BPS: u32 = 1_000;
+pub const DEFAULT_DODGE_CHANCE_BPS: u32 = 500;
+pub const DEFAULT_LOOT_DROP_CHANCE_BPS: u32 = 2_000;
+
+/// Stardust Points a battle winner receives on a successful loot roll
+pub const LOOT_STARDUST_POINTS: u64 = 25;
+
+/// Battle Randomness Module for StardustEngine
+/// Critical hit, dodge and loot rolls from the chain randomness source or a commit-reveal seed
+#[multiversx_sc::module]
+pub trait BattleRandomness: access_control::AccessControl {
+    
+    #[endpoint]
+    fn set_combat_chances(&self, critical_chance_bps: u32, dodge_chance_bps: u32, loot_drop_chance_bps: u32) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(critical_chance_bps + dodge_chance_bps <= ROLL_RANGE, "Chances exceed roll range");
+        require!(loot_drop_chance_bps <= ROLL_RANGE, "Chances exceed roll range");
+        
+        let chances = CombatChances {
+            critical_chance_bps,
+            dodge_chance_bps,
+            loot_drop_chance_bps,
+        };
+        self.combat_chances().set(&chances);
+        self.combat_chances_set_event(&chances);
+    }
+    
+    /// Draws a roll in `0..ROLL_RANGE`.
+    /// Commit-reveal battles derive it from keccak256(seed ++ block_random_seed ++ nonce), so both
+    /// players can verify it afterwards but neither can predict it before the move's block,
+    /// other battles use the chain randomness source.
+    fn roll_battle(&self, battle_id: u64, commit_reveal: bool, nonce: u32) -> u32 {
+        if !commit_reveal {
+            return RandomnessSource::new().next_u32_in_range(0, ROLL_RANGE);
+        }
+        
+        let mut data = self.battle_seed(battle_id).get();
+        data.append(self.blockchain().get_block_random_seed().as_managed_buffer());
+        data.append_bytes(&nonce.to_be_bytes());
+        let hash = self.crypto().keccak256(&data).to_byte_array();
+        u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]]) % ROLL_RANGE
+    }
+    
+    /// Stores a revealed secret. Once both players revealed, the battle seed is
+    /// keccak256(attacker_secret ++ defender_secret).
+    fn reveal_secret(
+        &self,
+        battle_id: u64,
+        attacker: &ManagedAddress,
+        defender: &ManagedAddress,
+        player: &ManagedAddress,
+        secret: ManagedBuffer,
+    ) {
+        let commitment_mapper = self.battle_commitment(battle_id, player);
+        require!(!commitment_mapper.is_empty(), "No commitment to reveal");
+        require!(self.battle_secret(battle_id, player).is_empty(), "Secret already revealed");
+        require!(self.crypto().keccak256(&secret) == commitment_mapper.get(), "Secret does not match commitment");
+        
+        self.battle_secret(battle_id, player).set(&secret);
+        self.battle_secret_revealed_event(battle_id, player);
+        
+        let attacker_secret = self.battle_secret(battle_id, attacker);
+        let defender_secret = self.battle_secret(battle_id, defender);
+        if attacker_secret.is_empty() || defender_secret.is_empty() {
+            return;
+        }
+        
+        let mut seed = attacker_secret.get();
+        seed.append(&defender_secret.get());
+        self.battle_seed(battle_id).set(self.crypto().keccak256(&seed).as_managed_buffer());
+    }
+    
+    fn is_battle_seed_ready(&self, battle_id: u64) -> bool {
+        !self.battle_seed(battle_id).is_empty()
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_combat_chances(&self) -> CombatChances {
+        if self.combat_chances().is_empty() {
+            return CombatChances {
+                critical_chance_bps: DEFAULT_CRITICAL_CHANCE_BPS,
+                dodge_chance_bps: DEFAULT_DODGE_CHANCE_BPS,
+                loot_drop_chance_bps: DEFAULT_LOOT_DROP_CHANCE_BPS,
+            };
+        }
+        self.combat_chances().get()
+    }
+    
+    #[view]
+    fn get_battle_seed(&self, battle_id: u64) -> ManagedBuffer {
+        self.battle_seed(battle_id).get()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("combatChances")]
+    fn combat_chances(&self) -> SingleValueMapper<CombatChances>;
+    
+    #[storage_mapper("battleCommitment")]
+    fn battle_commitment(&self, battle_id: u64, player: &ManagedAddress) -> SingleValueMapper<ManagedByteArray<32>>;
+    
+    #[storage_mapper("battleSecret")]
+    fn battle_secret(&self, battle_id: u64, player: &ManagedAddress) -> SingleValueMapper<ManagedBuffer>;
+    
+    #[storage_mapper("battleSeed")]
+    fn battle_seed(&self, battle_id: u64) -> SingleValueMapper<ManagedBuffer>;
+    
+    // ===== EVENTS =====
+    
+    #[event("combatChancesSet")]
+    fn combat_chances_set_event(&self, chances: &CombatChances);
+    
+    #[event("battleSecretRevealed")]
+    fn battle_secret_revealed_event(
+        &self,
+        #[indexed] battle_id: u64,
+        #[indexed] player: &ManagedAddress,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct CombatChances {
+    pub critical_chance_bps: u32,
+    pub dodge_chance_bps: u32,
+    pub loot_drop_chance_bps: u32,
+}
@@ -1,31 +1,125 @@
 use multiversx_sc::imports::*;
-use crate::stardust_contracts::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::asset_storage::{self, AssetLock, GameAsset};
+use crate::battle_randomness::{self, LOOT_STARDUST_POINTS, ROLL_RANGE};
+use crate::pause::{self, Subsystem};
+use crate::tournament_system::TOTAL_BASIS_POINTS;
+use crate::treasury::{self, RevenueSource};
+use crate::access_control::Role;
+use crate::seasons::{self, LeaderboardMetric};
+use crate::stardust_token::StardustFlow;
+use crate::{access_control, asset_classes, level_calculation, level_rewards, player_storage, progression, ranking, stardust_token};
+
+/// Energy every combatant enters a battle with
+pub const STARTING_ENERGY: u32 = 2;
+
+/// Energy cap, Attack and Defend each restore one point
+pub const MAX_ENERGY: u32 = 5;
+
+pub const SPECIAL_ENERGY_COST: u32 = 2;
+pub const COMBO_ENERGY_COST: u32 = 3;
+
+/// Dodge chance in basis points a combatant gains per point of speed
+pub const SPEED_DODGE_BPS: u32 = 20;
+
+/// Turn limit after which the side with more remaining HP wins
+pub const MAX_BATTLE_TURNS: u32 = 30;
+
+/// Seconds a defender has to accept, used until a game master configures one
+pub const DEFAULT_ACCEPTANCE_TIMEOUT: u64 = 86_400;
+
+/// Seconds a player has to act on their turn, used until a game master configures one
+pub const DEFAULT_TURN_TIMEOUT: u64 = 3_600;
+
+/// Upper bound for the rake taken from wagered battle pots
+pub const MAX_BATTLE_RAKE_BPS: u64 = 2_000;
+
+/// Seconds before the same two players can battle again, used until a game master configures one
+pub const DEFAULT_REMATCH_COOLDOWN: u64 = 300;
+
+/// Experience a player can earn from battles per day, used until a game master configures one
+pub const DEFAULT_DAILY_BATTLE_EXPERIENCE_CAP: u64 = 1_000;
+
+pub const SECONDS_PER_DAY: u64 = 86_400;
 
 /// Combat System Module for StardustEngine
 /// Implements turn-based strategic combat with NFT assets
-pub trait CombatSystem {
+#[multiversx_sc::module]
+pub trait CombatSystem:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + level_calculation::LevelCalculation
+    + level_rewards::LevelRewards
+    + pause::Pause
+    + battle_randomness::BattleRandomness
+    + treasury::Treasury
+    + ranking::Ranking
+    + seasons::Seasons
+    + progression::Progression
+    + stardust_token::StardustToken
+{
     
     // ===== COMBAT MECHANICS =====
     
+    /// Challenges `opponent` with the game asset NFTs sent along with the call.
+    /// The contract holds the lineup until the battle is resolved.
+    /// Any other EGLD or fungible ESDT payment in the call is a wager the defender has to match.
+    /// Passing a keccak256 commitment makes it a commit-reveal battle, see `reveal_battle_secret`.
+    /// Tournament battles are started from their bracket match with `initiate_tournament_battle`.
     #[endpoint]
+    #[payable("*")]
     fn initiate_battle(
         &self,
         opponent: ManagedAddress,
-        attacker_assets: ManagedVec<u64>,
         battle_type: BattleType,
+        commitment: OptionalValue<ManagedByteArray<32>>,
     ) -> u64 {
+        require!(battle_type != BattleType::Tournament, "Tournament battles start from a bracket match");
         let caller = self.blockchain().get_caller();
-        require!(self.players().contains(&caller), "Player not registered");
+        self.start_battle(&caller, opponent, battle_type, commitment.into_option())
+    }
+    
+    /// Creates a challenge from `caller` to `opponent` with the lineup and wager sent along with the call.
+    /// Tournament battles skip the rematch cooldown, their bracket match decides who plays whom.
+    fn start_battle(
+        &self,
+        caller: &ManagedAddress,
+        opponent: ManagedAddress,
+        battle_type: BattleType,
+        commitment: Option<ManagedByteArray<32>>,
+    ) -> u64 {
+        self.require_not_paused(Subsystem::Battles);
+        require!(!self.blockchain().is_smart_contract(caller), "Only user accounts");
+        require!(self.players().contains(caller), "Player not registered");
         require!(self.players().contains(&opponent), "Opponent not registered");
-        require!(attacker_assets.len() <= 3, "Maximum 3 assets per battle");
-        
-        // Validate asset ownership
-        for asset_id in attacker_assets.iter() {
-            require!(self.asset_owner(asset_id).get() == caller, "Asset not owned");
+        require!(*caller != opponent, "Cannot battle yourself");
+        if battle_type != BattleType::Tournament {
+            self.require_rematch_allowed(caller, &opponent);
+        }
+        let (attacker_assets, wager) = self.receive_battle_payments(caller);
+        require!(
+            wager.is_none() || battle_type != BattleType::Tournament,
+            "Tournament battles cannot be wagered"
+        );
+        if let Some(wager) = &wager {
+            self.add_escrow(&wager.token_identifier, &wager.amount);
         }
+        self.lock_battle_assets(&attacker_assets, &battle_type);
         
         let battle_id = self.next_battle_id().get();
         self.next_battle_id().set(battle_id + 1);
+        let now = self.blockchain().get_block_timestamp();
+        if battle_type != BattleType::Tournament {
+            self.open_battle_between(&self.player_pair(caller, &opponent)).set(battle_id);
+        }
+        
+        let commit_reveal = commitment.is_some();
+        if let Some(commitment) = commitment {
+            self.battle_commitment(battle_id, caller).set(commitment);
+        }
         
         let battle = Battle {
             id: battle_id,
@@ -33,42 +127,285 @@ pub trait CombatSystem {
             defender: opponent.clone(),
             attacker_assets: attacker_assets.clone(),
             defender_assets: ManagedVec::new(),
+            attacker_combatants: ManagedVec::new(),
+            defender_combatants: ManagedVec::new(),
             battle_type,
             status: BattleStatus::WaitingForDefender,
             turn: 1u32,
-            created_at: self.blockchain().get_block_timestamp(),
+            created_at: now,
+            last_action_at: now,
             moves: ManagedVec::new(),
+            winner: None,
+            commit_reveal,
+            wager,
+            rake_bps: self.battle_rake_bps().get(),
         };
         
         self.battles().insert(battle_id);
         self.battle_data(battle_id).set(battle);
         
-        self.battle_initiated_event(&caller, &opponent, battle_id);
+        self.battle_initiated_event(caller, &opponent, battle_id);
         battle_id
     }
     
+    /// Accepts a challenge with the game asset NFTs sent along with the call.
+    /// A wagered battle requires the same wager, commit-reveal battles the defender's commitment.
     #[endpoint]
-    fn accept_battle(&self, battle_id: u64, defender_assets: ManagedVec<u64>) {
+    #[payable("*")]
+    fn accept_battle(&self, battle_id: u64, commitment: OptionalValue<ManagedByteArray<32>>) {
+        self.require_not_paused(Subsystem::Battles);
         let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Only user accounts");
         require!(self.battles().contains(&battle_id), "Battle not found");
         
         let mut battle = self.battle_data(battle_id).get();
         require!(battle.defender == caller, "Not the defender");
         require!(battle.status == BattleStatus::WaitingForDefender, "Battle already accepted");
-        require!(defender_assets.len() <= 3, "Maximum 3 assets per battle");
+        let now = self.blockchain().get_block_timestamp();
+        require!(now <= self.get_acceptance_deadline(&battle), "Acceptance deadline passed");
+        let (defender_assets, defender_wager) = self.receive_battle_payments(&caller);
+        match (&battle.wager, defender_wager) {
+            (Some(wager), Some(defender_wager)) => {
+                require!(
+                    defender_wager.token_identifier == wager.token_identifier && defender_wager.amount == wager.amount,
+                    "Wager mismatch"
+                );
+                self.add_escrow(&wager.token_identifier, &wager.amount);
+            },
+            (Some(_), None) => sc_panic!("Wager must be matched"),
+            (None, Some(_)) => sc_panic!("Battle has no wager"),
+            (None, None) => {},
+        }
         
-        // Validate asset ownership
-        for asset_id in defender_assets.iter() {
-            require!(self.asset_owner(asset_id).get() == caller, "Asset not owned");
+        match commitment.into_option() {
+            Some(commitment) => {
+                require!(battle.commit_reveal, "Battle does not use commit-reveal");
+                self.battle_commitment(battle_id, &caller).set(commitment);
+            },
+            None => require!(!battle.commit_reveal, "Commitment required"),
         }
+        self.lock_battle_assets(&defender_assets, &battle.battle_type);
         
+        // Combat stats are snapshotted here, later asset changes do not affect a running battle
+        battle.attacker_combatants = self.create_combatants(&battle.attacker_assets);
+        battle.defender_combatants = self.create_combatants(&defender_assets);
         battle.defender_assets = defender_assets;
         battle.status = BattleStatus::Active;
+        battle.last_action_at = now;
         
         self.battle_data(battle_id).set(battle);
         self.battle_accepted_event(&caller, battle_id);
     }
     
+    /// Cancels a challenge that has not been accepted yet and returns the attacker's lineup and wager.
+    /// The defender can also cancel once the acceptance deadline has passed,
+    /// so an abandoned challenge does not block the pair from battling again.
+    /// Stays open while battles are paused, so the attacker can always get the lineup back.
+    #[endpoint]
+    fn cancel_battle(&self, battle_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(self.battles().contains(&battle_id), "Battle not found");
+        
+        let mut battle = self.battle_data(battle_id).get();
+        require!(caller == battle.attacker || caller == battle.defender, "Not a battle participant");
+        require!(battle.status == BattleStatus::WaitingForDefender, "Battle not waiting for defender");
+        if caller == battle.defender {
+            let now = self.blockchain().get_block_timestamp();
+            require!(
+                now > self.get_acceptance_deadline(&battle),
+                "Only the attacker can cancel before the acceptance deadline"
+            );
+        }
+        
+        battle.status = BattleStatus::Cancelled;
+        self.battle_data(battle_id).set(&battle);
+        if battle.battle_type != BattleType::Tournament {
+            self.open_battle_between(&self.player_pair(&battle.attacker, &battle.defender)).clear();
+        }
+        self.return_battle_assets(&battle);
+        
+        if let Some(wager) = &battle.wager {
+            self.release_escrow(&wager.token_identifier, &wager.amount);
+            self.send().direct(&battle.attacker, &wager.token_identifier, 0, &wager.amount);
+            self.battle_wager_refunded_event(&battle.attacker, battle_id, &wager.amount);
+        }
+        
+        self.battle_cancelled_event(&caller, battle_id);
+    }
+    
+    /// Wins an active battle whose opponent let the turn deadline pass.
+    /// In a commit-reveal battle that is not seeded yet, the opponent is stalling if they have not revealed.
+    #[endpoint]
+    fn claim_battle_forfeit(&self, battle_id: u64) {
+        self.require_not_paused(Subsystem::Battles);
+        let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Only user accounts");
+        require!(self.battles().contains(&battle_id), "Battle not found");
+        
+        let mut battle = self.battle_data(battle_id).get();
+        require!(battle.status == BattleStatus::Active, "Battle not active");
+        require!(caller == battle.attacker || caller == battle.defender, "Not a battle participant");
+        
+        let now = self.blockchain().get_block_timestamp();
+        require!(now > self.get_turn_deadline(&battle), "Turn deadline not reached");
+        
+        let opponent = if caller == battle.attacker { battle.defender.clone() } else { battle.attacker.clone() };
+        if battle.commit_reveal && !self.is_battle_seed_ready(battle_id) {
+            require!(!self.battle_secret(battle_id, &caller).is_empty(), "Secret not revealed");
+        } else {
+            let is_attacker_turn = battle.turn % 2 == 1;
+            let player_to_move = if is_attacker_turn { &battle.attacker } else { &battle.defender };
+            require!(*player_to_move == opponent, "Cannot forfeit on your own turn");
+        }
+        
+        self.battle_forfeited_event(&caller, &opponent, battle_id);
+        self.complete_battle(&mut battle, caller);
+    }
+    
+    #[endpoint]
+    fn set_battle_timeouts(&self, acceptance_timeout: u64, turn_timeout: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(acceptance_timeout > 0 && turn_timeout > 0, "Timeouts must be positive");
+        
+        self.battle_acceptance_timeout().set(acceptance_timeout);
+        self.battle_turn_timeout().set(turn_timeout);
+        self.battle_timeouts_set_event(acceptance_timeout, turn_timeout);
+    }
+    
+    #[endpoint]
+    fn set_battle_limits(&self, rematch_cooldown: u64, daily_experience_cap: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        
+        self.rematch_cooldown().set(rematch_cooldown);
+        self.daily_battle_experience_cap().set(daily_experience_cap);
+        self.battle_limits_set_event(rematch_cooldown, daily_experience_cap);
+    }
+    
+    #[endpoint]
+    fn set_battle_rake(&self, rake_bps: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(rake_bps <= MAX_BATTLE_RAKE_BPS, "Battle rake too high");
+        
+        self.battle_rake_bps().set(rake_bps);
+        self.battle_rake_set_event(rake_bps);
+    }
+    
+    fn get_battle_acceptance_timeout(&self) -> u64 {
+        if self.battle_acceptance_timeout().is_empty() {
+            DEFAULT_ACCEPTANCE_TIMEOUT
+        } else {
+            self.battle_acceptance_timeout().get()
+        }
+    }
+    
+    fn get_battle_turn_timeout(&self) -> u64 {
+        if self.battle_turn_timeout().is_empty() {
+            DEFAULT_TURN_TIMEOUT
+        } else {
+            self.battle_turn_timeout().get()
+        }
+    }
+    
+    /// Deadlines restart when battles come back from a pause, a pause never runs out a player's time.
+    fn get_acceptance_deadline(&self, battle: &Battle<Self::Api>) -> u64 {
+        let resumed_at = self.get_resumed_at(&Subsystem::Battles);
+        core::cmp::max(battle.created_at, resumed_at) + self.get_battle_acceptance_timeout()
+    }
+    
+    fn get_turn_deadline(&self, battle: &Battle<Self::Api>) -> u64 {
+        let resumed_at = self.get_resumed_at(&Subsystem::Battles);
+        core::cmp::max(battle.last_action_at, resumed_at) + self.get_battle_turn_timeout()
+    }
+    
+    fn get_rematch_cooldown(&self) -> u64 {
+        if self.rematch_cooldown().is_empty() {
+            DEFAULT_REMATCH_COOLDOWN
+        } else {
+            self.rematch_cooldown().get()
+        }
+    }
+    
+    fn get_daily_battle_experience_cap(&self) -> u64 {
+        if self.daily_battle_experience_cap().is_empty() {
+            DEFAULT_DAILY_BATTLE_EXPERIENCE_CAP
+        } else {
+            self.daily_battle_experience_cap().get()
+        }
+    }
+    
+    /// A pair can battle again once their open battle is over and the cooldown since their last one passed.
+    fn is_rematch_allowed(&self, player: &ManagedAddress, opponent: &ManagedAddress) -> bool {
+        let player_pair = self.player_pair(player, opponent);
+        if !self.open_battle_between(&player_pair).is_empty() {
+            return false;
+        }
+        
+        let last_battle_mapper = self.last_battle_between(&player_pair);
+        last_battle_mapper.is_empty()
+            || self.blockchain().get_block_timestamp() >= last_battle_mapper.get() + self.get_rematch_cooldown()
+    }
+    
+    fn require_rematch_allowed(&self, player: &ManagedAddress, opponent: &ManagedAddress) {
+        require!(self.is_rematch_allowed(player, opponent), "Rematch cooldown active");
+    }
+    
+    /// Both players of a pair in a fixed order, so the pair has one storage key.
+    fn player_pair(&self, player: &ManagedAddress, opponent: &ManagedAddress) -> ManagedBuffer {
+        let (first, second) = if player.to_byte_array() < opponent.to_byte_array() {
+            (player, opponent)
+        } else {
+            (opponent, player)
+        };
+        let mut pair = first.as_managed_buffer().clone();
+        pair.append(second.as_managed_buffer());
+        pair
+    }
+    
+    /// Awards battle experience up to what is left of the player's daily cap.
+    fn add_battle_experience(&self, player: &ManagedAddress, amount: u64) {
+        let day = self.blockchain().get_block_timestamp() / SECONDS_PER_DAY;
+        let earned = self.daily_battle_experience(player, day).get();
+        let amount = core::cmp::min(amount, self.get_daily_battle_experience_cap().saturating_sub(earned));
+        if amount == 0 {
+            return;
+        }
+        
+        self.daily_battle_experience(player, day).set(earned + amount);
+        self.add_player_experience(player, amount);
+    }
+    
+    /// Levels bought with upgrades are kept when the curve puts the experience below them.
+    fn add_asset_experience(&self, asset_id: u64, amount: u64) {
+        let mut asset = self.asset_metadata(asset_id).get();
+        let old_level = asset.level;
+        asset.experience += amount;
+        asset.level = core::cmp::max(asset.level, self.calculate_asset_level(asset.experience));
+        self.save_game_asset(&asset);
+        if asset.level > old_level {
+            self.asset_level_up_event(asset_id, old_level, asset.level);
+        }
+    }
+    
+    /// Reveals the secret behind a commitment. Moves are allowed once both players revealed,
+    /// rolls are then derived from both secrets so neither player can pick them alone.
+    #[endpoint]
+    fn reveal_battle_secret(&self, battle_id: u64, secret: ManagedBuffer) {
+        self.require_not_paused(Subsystem::Battles);
+        let caller = self.blockchain().get_caller();
+        require!(self.battles().contains(&battle_id), "Battle not found");
+        
+        let battle = self.battle_data(battle_id).get();
+        require!(battle.commit_reveal, "Battle does not use commit-reveal");
+        require!(battle.status == BattleStatus::Active, "Battle not active");
+        require!(caller == battle.attacker || caller == battle.defender, "Not a battle participant");
+        
+        self.reveal_secret(battle_id, &battle.attacker, &battle.defender, &caller, secret);
+        
+        let mut battle = battle;
+        battle.last_action_at = self.blockchain().get_block_timestamp();
+        self.battle_data(battle_id).set(battle);
+    }
+    
     #[endpoint]
     fn make_move(
         &self,
@@ -77,20 +414,48 @@ pub trait CombatSystem {
         move_type: MoveType,
         target_asset: OptionalValue<u64>,
     ) {
+        self.require_not_paused(Subsystem::Battles);
         let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Only user accounts");
         require!(self.battles().contains(&battle_id), "Battle not found");
         
         let mut battle = self.battle_data(battle_id).get();
         require!(battle.status == BattleStatus::Active, "Battle not active");
+        require!(!battle.commit_reveal || self.is_battle_seed_ready(battle_id), "Battle secrets not revealed");
         
         // Verify it's player's turn
         let is_attacker_turn = battle.turn % 2 == 1;
-        if is_attacker_turn {
+        let (mut own_side, mut opposing_side) = if is_attacker_turn {
             require!(battle.attacker == caller, "Not your turn");
-            require!(battle.attacker_assets.contains(&asset_id), "Asset not in battle");
+            (battle.attacker_combatants.clone(), battle.defender_combatants.clone())
         } else {
             require!(battle.defender == caller, "Not your turn");
-            require!(battle.defender_assets.contains(&asset_id), "Asset not in battle");
+            (battle.defender_combatants.clone(), battle.attacker_combatants.clone())
+        };
+        
+        let target_asset = target_asset.into_option();
+        let hits = if move_type == MoveType::UseItem {
+            self.use_consumable(&mut battle, is_attacker_turn, asset_id, target_asset, &mut own_side);
+            ManagedVec::new()
+        } else {
+            let actor_index = match self.find_combatant(&own_side, asset_id) {
+                Some(index) => index,
+                None => sc_panic!("Asset not in battle"),
+            };
+            let mut actor = own_side.get(actor_index).clone();
+            require!(actor.hp > 0, "Asset knocked out");
+            
+            let hits = self.apply_move(&battle, &mut actor, &move_type, target_asset, &mut opposing_side);
+            let _ = own_side.set(actor_index, actor);
+            hits
+        };
+        
+        if is_attacker_turn {
+            battle.attacker_combatants = own_side;
+            battle.defender_combatants = opposing_side;
+        } else {
+            battle.defender_combatants = own_side;
+            battle.attacker_combatants = opposing_side;
         }
         
         let battle_move = BattleMove {
@@ -98,79 +463,388 @@ pub trait CombatSystem {
             player: caller.clone(),
             asset_id,
             move_type: move_type.clone(),
-            target_asset: target_asset.into_option(),
+            target_asset,
             timestamp: self.blockchain().get_block_timestamp(),
+            hits,
         };
         
+        battle.last_action_at = battle_move.timestamp;
         battle.moves.push(battle_move);
         battle.turn += 1;
         
-        // Auto-resolve battle after certain number of turns or conditions
-        if battle.turn > 10 || self.check_battle_end_conditions(&battle) {
-            battle.status = BattleStatus::Completed;
+        self.move_made_event(&caller, battle_id, asset_id, &move_type);
+        
+        if battle.turn > MAX_BATTLE_TURNS || self.check_battle_end_conditions(&battle) {
             let winner = self.calculate_battle_winner(&battle);
-            self.resolve_battle(battle_id, winner);
+            self.complete_battle(&mut battle, winner);
+        } else {
+            self.battle_data(battle_id).set(&battle);
         }
+    }
+    
+    // ===== RANKED MATCHMAKING =====
+    
+    /// Queues for a ranked battle with the game asset NFTs sent along with the call.
+    /// If a queued player's rating is within the rating window, the battle starts right away
+    /// with the queued player as attacker, otherwise the caller waits in the queue.
+    #[endpoint]
+    #[payable("*")]
+    fn join_ranked_queue(&self) -> OptionalValue<u64> {
+        self.require_not_paused(Subsystem::Battles);
+        let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Only user accounts");
+        require!(self.players().contains(&caller), "Player not registered");
+        require!(!self.ranked_queue().contains(&caller), "Already in ranked queue");
+        let (assets, wager) = self.receive_battle_payments(&caller);
+        require!(wager.is_none(), "Ranked queue battles cannot be wagered");
+        self.lock_battle_assets(&assets, &BattleType::Ranked);
         
+        let rating = self.get_player_rating(caller.clone());
+        let opponent = match self.find_ranked_opponent(&caller, rating) {
+            Some(opponent) => opponent,
+            None => {
+                self.ranked_queue().insert(caller.clone());
+                self.ranked_queue_assets(&caller).set(assets);
+                self.ranked_queue_joined_event(&caller, rating);
+                return OptionalValue::None;
+            },
+        };
+        
+        self.ranked_queue().remove(&opponent);
+        let opponent_assets = self.ranked_queue_assets(&opponent).take();
+        
+        let battle_id = self.next_battle_id().get();
+        self.next_battle_id().set(battle_id + 1);
+        let now = self.blockchain().get_block_timestamp();
+        
+        let battle = Battle {
+            id: battle_id,
+            attacker: opponent.clone(),
+            defender: caller.clone(),
+            attacker_combatants: self.create_combatants(&opponent_assets),
+            defender_combatants: self.create_combatants(&assets),
+            attacker_assets: opponent_assets,
+            defender_assets: assets,
+            battle_type: BattleType::Ranked,
+            status: BattleStatus::Active,
+            turn: 1u32,
+            created_at: now,
+            last_action_at: now,
+            moves: ManagedVec::new(),
+            winner: None,
+            commit_reveal: false,
+            wager: None,
+            rake_bps: 0,
+        };
+        
+        self.battles().insert(battle_id);
         self.battle_data(battle_id).set(battle);
-        self.move_made_event(&caller, battle_id, asset_id, &move_type);
+        self.open_battle_between(&self.player_pair(&caller, &opponent)).set(battle_id);
+        
+        self.ranked_match_found_event(&opponent, &caller, battle_id);
+        OptionalValue::Some(battle_id)
     }
     
-    fn calculate_battle_winner(&self, battle: &Battle<Self::Api>) -> ManagedAddress {
-        let mut attacker_power = 0u32;
-        let mut defender_power = 0u32;
+    /// Leaves the ranked queue and returns the queued lineup, also while battles are paused.
+    #[endpoint]
+    fn leave_ranked_queue(&self) {
+        let caller = self.blockchain().get_caller();
+        require!(self.ranked_queue().remove(&caller), "Not in ranked queue");
         
-        // Calculate total power based on assets and moves
-        for asset_id in battle.attacker_assets.iter() {
-            let asset = self.asset_metadata(asset_id).get();
-            attacker_power += self.calculate_asset_power(&asset);
+        let token_id = self.game_asset_token().get_token_id();
+        let amount = BigUint::from(1u32);
+        for asset_id in self.ranked_queue_assets(&caller).take().iter() {
+            self.unlock_asset(asset_id);
+            self.send().direct_esdt(&caller, &token_id, asset_id, &amount);
         }
         
-        for asset_id in battle.defender_assets.iter() {
-            let asset = self.asset_metadata(asset_id).get();
-            defender_power += self.calculate_asset_power(&asset);
+        self.ranked_queue_left_event(&caller);
+    }
+    
+    /// The longest waiting queued player whose rating is within the rating window
+    /// and who is not on rematch cooldown with the player.
+    fn find_ranked_opponent(&self, player: &ManagedAddress, rating: u32) -> Option<ManagedAddress> {
+        let rating_window = self.get_ranked_rating_window();
+        self.ranked_queue().iter().find(|queued| {
+            let queued_rating = self.get_player_rating(queued.clone());
+            queued_rating.abs_diff(rating) <= rating_window && self.is_rematch_allowed(player, queued)
+        })
+    }
+    
+    fn complete_battle(&self, battle: &mut Battle<Self::Api>, winner: ManagedAddress) {
+        battle.status = BattleStatus::Completed;
+        battle.winner = Some(winner.clone());
+        self.battle_data(battle.id).set(&*battle);
+        self.resolve_battle(battle.id, winner);
+    }
+    
+    /// Applies a move of `actor` against the opposing side.
+    /// Attack and Special hit the targeted asset, Combo hits every standing opposing asset,
+    /// Defend halves the damage the actor takes until it acts again.
+    /// Returns the hits with their rolls so the move can be replayed.
+    fn apply_move(
+        &self,
+        battle: &Battle<Self::Api>,
+        actor: &mut Combatant,
+        move_type: &MoveType,
+        target_asset: Option<u64>,
+        opposing_side: &mut ManagedVec<Combatant>,
+    ) -> ManagedVec<MoveHit> {
+        actor.defending = false;
+        let mut hits = ManagedVec::new();
+        
+        match move_type {
+            MoveType::Attack => {
+                let target_index = self.require_target(opposing_side, target_asset);
+                hits.push(self.hit_combatant(battle, actor, opposing_side, target_index, actor.attack, 0));
+                actor.energy = core::cmp::min(actor.energy + 1, MAX_ENERGY);
+            },
+            MoveType::Defend => {
+                actor.defending = true;
+                actor.energy = core::cmp::min(actor.energy + 1, MAX_ENERGY);
+            },
+            MoveType::Special => {
+                require!(actor.energy >= SPECIAL_ENERGY_COST, "Not enough energy");
+                let target_index = self.require_target(opposing_side, target_asset);
+                actor.energy -= SPECIAL_ENERGY_COST;
+                hits.push(self.hit_combatant(battle, actor, opposing_side, target_index, actor.attack * 2 + actor.special, 0));
+            },
+            MoveType::Combo => {
+                require!(actor.energy >= COMBO_ENERGY_COST, "Not enough energy");
+                actor.energy -= COMBO_ENERGY_COST;
+                for target_index in 0..opposing_side.len() {
+                    if opposing_side.get(target_index).hp > 0 {
+                        let hit_index = hits.len() as u32;
+                        hits.push(self.hit_combatant(battle, actor, opposing_side, target_index, actor.attack, hit_index));
+                    }
+                }
+            },
+            MoveType::UseItem => sc_panic!("Items are used with use_consumable"),
         }
         
-        // Factor in move strategy and synergies
-        attacker_power += self.calculate_move_bonus(&battle.moves, &battle.attacker);
-        defender_power += self.calculate_move_bonus(&battle.moves, &battle.defender);
+        hits
+    }
+    
+    /// Restores HP of a standing own asset by the consumable's special stat.
+    /// The consumable leaves the lineup and is burned.
+    fn use_consumable(
+        &self,
+        battle: &mut Battle<Self::Api>,
+        is_attacker_turn: bool,
+        item_id: u64,
+        target_asset: Option<u64>,
+        own_side: &mut ManagedVec<Combatant>,
+    ) {
+        let player = if is_attacker_turn { battle.attacker.clone() } else { battle.defender.clone() };
+        let own_assets = if is_attacker_turn { &mut battle.attacker_assets } else { &mut battle.defender_assets };
+        let item_index = match own_assets.iter().position(|asset_id| asset_id == item_id) {
+            Some(index) => index,
+            None => sc_panic!("Asset not in battle"),
+        };
+        let item = self.asset_metadata(item_id).get();
+        require!(item.asset_type.is_consumable(), "Asset is not a consumable");
         
-        if attacker_power >= defender_power {
-            battle.attacker.clone()
-        } else {
-            battle.defender.clone()
-        }
+        let target_index = self.require_target(own_side, target_asset);
+        let mut target = own_side.get(target_index).clone();
+        let healed = core::cmp::min(item.stats.special, target.max_hp - target.hp);
+        target.hp += healed;
+        let _ = own_side.set(target_index, target.clone());
+        
+        own_assets.remove(item_index);
+        self.burn_game_asset(item_id, &player);
+        self.consumable_used_event(battle.id, item_id, target.asset_id, healed);
     }
     
-    fn calculate_asset_power(&self, asset: &GameAsset<Self::Api>) -> u32 {
-        let base_power = match asset.rarity {
-            Rarity::Common => 10,
-            Rarity::Rare => 25,
-            Rarity::Epic => 50,
-            Rarity::Legendary => 100,
+    fn require_target(&self, opposing_side: &ManagedVec<Combatant>, target_asset: Option<u64>) -> usize {
+        let target_asset = match target_asset {
+            Some(asset_id) => asset_id,
+            None => sc_panic!("Target required"),
         };
+        let target_index = match self.find_combatant(opposing_side, target_asset) {
+            Some(index) => index,
+            None => sc_panic!("Target not in battle"),
+        };
+        require!(opposing_side.get(target_index).hp > 0, "Target knocked out");
+        target_index
+    }
+    
+    /// Defense is subtracted from the raw damage, a defending target takes half.
+    /// One roll per hit decides a dodge (no damage) or a critical hit (double damage),
+    /// otherwise every hit deals at least 1 damage.
+    fn hit_combatant(
+        &self,
+        battle: &Battle<Self::Api>,
+        actor: &Combatant,
+        opposing_side: &mut ManagedVec<Combatant>,
+        target_index: usize,
+        raw_damage: u32,
+        hit_index: u32,
+    ) -> MoveHit {
+        let mut target = opposing_side.get(target_index).clone();
+        let chances = self.get_combat_chances();
+        let roll = self.roll_battle(battle.id, battle.commit_reveal, (battle.turn << 8) | hit_index);
+        let dodge_chance_bps = core::cmp::min(
+            chances.dodge_chance_bps + target.speed * SPEED_DODGE_BPS,
+            ROLL_RANGE - chances.critical_chance_bps,
+        );
+        let dodged = roll < dodge_chance_bps;
+        let critical = roll >= ROLL_RANGE - chances.critical_chance_bps;
+        
+        let mut damage = core::cmp::max(raw_damage.saturating_sub(target.defense), 1);
+        if target.defending {
+            damage = core::cmp::max(damage / 2, 1);
+        }
+        if critical {
+            damage *= 2;
+        }
+        if dodged {
+            damage = 0;
+        }
+        let damage = core::cmp::min(damage, target.hp);
+        target.hp -= damage;
+        
+        self.damage_dealt_event(battle.id, actor.asset_id, target.asset_id, damage);
+        if target.hp == 0 {
+            self.asset_knocked_out_event(battle.id, target.asset_id);
+        }
+        
+        let hit = MoveHit {
+            target_asset: target.asset_id,
+            roll,
+            critical,
+            dodged,
+            damage,
+        };
+        let _ = opposing_side.set(target_index, target);
+        hit
+    }
+    
+    fn find_combatant(&self, combatants: &ManagedVec<Combatant>, asset_id: u64) -> Option<usize> {
+        combatants.iter().position(|combatant| combatant.asset_id == asset_id)
+    }
+    
+    /// Splits the call payments into the game asset lineup and an optional wager.
+    /// The player sending the lineup is recorded as its owner.
+    fn receive_battle_payments(&self, player: &ManagedAddress) -> (ManagedVec<u64>, Option<EgldOrEsdtTokenPayment>) {
+        let asset_token_id = self.game_asset_token().get_token_id();
+        let mut assets = ManagedVec::new();
+        let mut wager = None;
+        
+        for payment in self.call_value().all_transfers().iter() {
+            if payment.token_identifier == asset_token_id {
+                require!(!assets.contains(&payment.token_nonce), "Duplicate asset in lineup");
+                self.set_asset_owner(payment.token_nonce, player);
+                assets.push(payment.token_nonce);
+                continue;
+            }
+            
+            require!(wager.is_none(), "Only one wager payment allowed");
+            require!(payment.token_nonce == 0, "Invalid wager token");
+            wager = Some(payment.clone());
+        }
         
-        let level_bonus = asset.level * 5;
-        let exp_bonus = (asset.experience / 100) as u32;
+        require!(!assets.is_empty(), "Lineup cannot be empty");
+        require!(assets.len() <= 3, "Maximum 3 assets per battle");
         
-        base_power + level_bonus + exp_bonus
+        let mut has_fighter = false;
+        for asset_id in assets.iter() {
+            let asset_type = self.asset_metadata(asset_id).get().asset_type;
+            require!(asset_type.can_fight() || asset_type.is_consumable(), "Asset type cannot battle");
+            has_fighter |= asset_type.can_fight();
+        }
+        require!(has_fighter, "Lineup needs a fighting asset");
+        (assets, wager)
     }
     
-    fn calculate_move_bonus(&self, moves: &ManagedVec<Self::Api, BattleMove<Self::Api>>, player: &ManagedAddress) -> u32 {
-        let mut bonus = 0u32;
+    /// Pays the pot of a wagered battle to the winner, minus the rake kept as revenue.
+    fn settle_battle_wager(&self, battle: &Battle<Self::Api>, winner: &ManagedAddress) {
+        let wager = match &battle.wager {
+            Some(wager) => wager,
+            None => return,
+        };
+        
+        let pot = &wager.amount * 2u32;
+        self.release_escrow(&wager.token_identifier, &pot);
         
-        for battle_move in moves.iter() {
-            if battle_move.player == *player {
-                bonus += match battle_move.move_type {
-                    MoveType::Attack => 10,
-                    MoveType::Defend => 5,
-                    MoveType::Special => 15,
-                    MoveType::Combo => 20,
-                };
+        let rake = &pot * battle.rake_bps / TOTAL_BASIS_POINTS;
+        self.record_revenue(RevenueSource::BattleRake, &wager.token_identifier, &rake);
+        
+        let payout = pot - rake;
+        self.send().direct(winner, &wager.token_identifier, 0, &payout);
+        self.battle_wager_paid_event(winner, battle.id, &payout);
+    }
+    
+    /// Tournament battle lineups are locked as in tournament, every other lineup as in battle.
+    fn lock_battle_assets(&self, assets: &ManagedVec<u64>, battle_type: &BattleType) {
+        let lock = if *battle_type == BattleType::Tournament { AssetLock::InTournament } else { AssetLock::InBattle };
+        for asset_id in assets.iter() {
+            self.lock_asset(asset_id, lock.clone());
+        }
+    }
+    
+    fn return_battle_assets(&self, battle: &Battle<Self::Api>) {
+        let token_id = self.game_asset_token().get_token_id();
+        let amount = BigUint::from(1u32);
+        for asset_id in battle.attacker_assets.iter() {
+            self.unlock_asset(asset_id);
+            self.send().direct_esdt(&battle.attacker, &token_id, asset_id, &amount);
+        }
+        for asset_id in battle.defender_assets.iter() {
+            self.unlock_asset(asset_id);
+            self.send().direct_esdt(&battle.defender, &token_id, asset_id, &amount);
+        }
+    }
+    
+    /// Consumables in the lineup do not fight, they stay available for `MoveType::UseItem`.
+    fn create_combatants(&self, assets: &ManagedVec<u64>) -> ManagedVec<Combatant> {
+        let mut combatants = ManagedVec::new();
+        for asset_id in assets.iter() {
+            let asset = self.asset_metadata(asset_id).get();
+            if asset.asset_type.can_fight() {
+                combatants.push(self.create_combatant(&asset));
             }
         }
+        combatants
+    }
+    
+    /// Level and experience add the same bonus to attack and defense, HP is four times the defense
+    /// before it is divided down to damage reduction.
+    fn create_combatant(&self, asset: &GameAsset<Self::Api>) -> Combatant {
+        let level_bonus = self.calculate_level_bonus(asset);
+        let attack = asset.stats.attack + level_bonus;
+        let toughness = asset.stats.defense + level_bonus;
+        Combatant {
+            asset_id: asset.id,
+            hp: toughness * 4,
+            max_hp: toughness * 4,
+            attack,
+            defense: toughness / 3,
+            speed: asset.stats.speed,
+            special: asset.stats.special,
+            energy: STARTING_ENERGY,
+            defending: false,
+        }
+    }
+    
+    /// The side with standing assets wins, at the turn limit the side with more remaining HP.
+    /// The attacker wins ties.
+    fn calculate_battle_winner(&self, battle: &Battle<Self::Api>) -> ManagedAddress {
+        let attacker_hp = self.total_remaining_hp(&battle.attacker_combatants);
+        let defender_hp = self.total_remaining_hp(&battle.defender_combatants);
         
-        bonus
+        if attacker_hp >= defender_hp {
+            battle.attacker.clone()
+        } else {
+            battle.defender.clone()
+        }
+    }
+    
+    fn total_remaining_hp(&self, combatants: &ManagedVec<Combatant>) -> u64 {
+        combatants.iter().map(|combatant| combatant.hp as u64).sum()
+    }
+    
+    fn calculate_asset_power(&self, asset: &GameAsset<Self::Api>) -> u32 {
+        (asset.stats.attack + asset.stats.defense) / 2 + self.calculate_level_bonus(asset)
     }
     
     fn resolve_battle(&self, battle_id: u64, winner: ManagedAddress) {
@@ -186,43 +860,58 @@ pub trait CombatSystem {
         let loser_exp = 25u64;
         
         self.player_stats(&winner).update(|stats| {
-            stats.experience += winner_exp;
             stats.games_played += 1;
             stats.games_won += 1;
-            stats.level = self.calculate_level(stats.experience);
         });
-        
         self.player_stats(&loser).update(|stats| {
-            stats.experience += loser_exp;
             stats.games_played += 1;
-            stats.level = self.calculate_level(stats.experience);
         });
+        self.add_battle_experience(&winner, winner_exp);
+        self.add_battle_experience(&loser, loser_exp);
+        if battle.battle_type != BattleType::Tournament {
+            let player_pair = self.player_pair(&winner, &loser);
+            self.open_battle_between(&player_pair).clear();
+            self.last_battle_between(&player_pair).set(self.blockchain().get_block_timestamp());
+        }
+        self.add_season_score(LeaderboardMetric::Wins, &winner, 1);
         
         // Award asset experience
         for asset_id in battle.attacker_assets.iter() {
-            self.asset_metadata(asset_id).update(|asset| {
-                asset.experience += if winner == battle.attacker { 50 } else { 10 };
-                asset.level = self.calculate_asset_level(asset.experience);
-            });
+            self.add_asset_experience(asset_id, if winner == battle.attacker { 50 } else { 10 });
         }
         
         for asset_id in battle.defender_assets.iter() {
-            self.asset_metadata(asset_id).update(|asset| {
-                asset.experience += if winner == battle.defender { 50 } else { 10 };
-                asset.level = self.calculate_asset_level(asset.experience);
-            });
+            self.add_asset_experience(asset_id, if winner == battle.defender { 50 } else { 10 });
         }
         
+        if battle.battle_type == BattleType::Ranked {
+            self.update_ratings(battle_id, &winner, &loser);
+            self.set_season_score(LeaderboardMetric::Rating, &winner, self.get_player_rating(winner.clone()) as u64);
+            self.set_season_score(LeaderboardMetric::Rating, &loser, self.get_player_rating(loser.clone()) as u64);
+        }
+        
+        self.roll_battle_loot(&battle, &winner);
+        self.settle_battle_wager(&battle, &winner);
+        self.return_battle_assets(&battle);
         self.battle_resolved_event(&winner, &loser, battle_id);
     }
     
-    fn calculate_asset_level(&self, experience: u64) -> u32 {
-        ((experience / 50).integer_sqrt() + 1).try_into().unwrap_or(1u32)
+    fn roll_battle_loot(&self, battle: &Battle<Self::Api>, winner: &ManagedAddress) {
+        // Hit rolls use nonces from turn 1 upwards, nonce 0 is reserved for the loot roll
+        let roll = self.roll_battle(battle.id, battle.commit_reveal, 0);
+        let dropped = roll < self.get_combat_chances().loot_drop_chance_bps;
+        let points = if dropped { LOOT_STARDUST_POINTS } else { 0 };
+        if dropped {
+            self.mint_stardust(winner, points, StardustFlow::BattleLoot);
+        }
+        
+        self.loot_rolled_event(battle.id, winner, roll, points);
     }
     
     fn check_battle_end_conditions(&self, battle: &Battle<Self::Api>) -> bool {
-        // Check for special end conditions
-        battle.moves.len() >= 20 // Maximum moves per battle
+        // A side without standing assets has lost
+        self.total_remaining_hp(&battle.attacker_combatants) == 0
+            || self.total_remaining_hp(&battle.defender_combatants) == 0
     }
     
     // ===== VIEW FUNCTIONS =====
@@ -248,6 +937,31 @@ pub trait CombatSystem {
         player_battles
     }
     
+    /// Deadline of the current phase: acceptance while waiting, the turn deadline while active.
+    #[view]
+    fn get_battle_deadline(&self, battle_id: u64) -> u64 {
+        require!(self.battles().contains(&battle_id), "Battle not found");
+        let battle = self.battle_data(battle_id).get();
+        match battle.status {
+            BattleStatus::WaitingForDefender => self.get_acceptance_deadline(&battle),
+            BattleStatus::Active => self.get_turn_deadline(&battle),
+            BattleStatus::Completed | BattleStatus::Cancelled => 0,
+        }
+    }
+    
+    /// Players waiting in the ranked queue, longest waiting first.
+    #[view]
+    fn get_ranked_queue(&self) -> MultiValueEncoded<ManagedAddress> {
+        self.ranked_queue().iter().collect()
+    }
+    
+    /// Battle experience the player earned today, counted against the daily cap.
+    #[view]
+    fn get_daily_battle_experience(&self, player: ManagedAddress) -> u64 {
+        let day = self.blockchain().get_block_timestamp() / SECONDS_PER_DAY;
+        self.daily_battle_experience(&player, day).get()
+    }
+    
     #[view]
     fn get_asset_power(&self, asset_id: u64) -> u32 {
         require!(self.game_assets().contains(&asset_id), "Asset not found");
@@ -266,6 +980,37 @@ pub trait CombatSystem {
     #[storage_mapper("nextBattleId")]
     fn next_battle_id(&self) -> SingleValueMapper<u64>;
     
+    #[storage_mapper("battleAcceptanceTimeout")]
+    fn battle_acceptance_timeout(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("battleTurnTimeout")]
+    fn battle_turn_timeout(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("battleRakeBps")]
+    fn battle_rake_bps(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("rematchCooldown")]
+    fn rematch_cooldown(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("dailyBattleExperienceCap")]
+    fn daily_battle_experience_cap(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("lastBattleBetween")]
+    fn last_battle_between(&self, player_pair: &ManagedBuffer) -> SingleValueMapper<u64>;
+    
+    /// Challenge or battle between a pair that is not resolved or cancelled yet, tournament battles excluded.
+    #[storage_mapper("openBattleBetween")]
+    fn open_battle_between(&self, player_pair: &ManagedBuffer) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("dailyBattleExperience")]
+    fn daily_battle_experience(&self, player: &ManagedAddress, day: u64) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("rankedQueue")]
+    fn ranked_queue(&self) -> SetMapper<ManagedAddress>;
+    
+    #[storage_mapper("rankedQueueAssets")]
+    fn ranked_queue_assets(&self, player: &ManagedAddress) -> SingleValueMapper<ManagedVec<u64>>;
+    
     // ===== EVENTS =====
     
     #[event("battleInitiated")]
@@ -288,10 +1033,98 @@ pub trait CombatSystem {
         &self,
         #[indexed] player: &ManagedAddress,
         #[indexed] battle_id: u64,
-        asset_id: u64,
+        #[indexed] asset_id: u64,
         move_type: &MoveType,
     );
     
+    #[event("damageDealt")]
+    fn damage_dealt_event(
+        &self,
+        #[indexed] battle_id: u64,
+        #[indexed] source_asset: u64,
+        #[indexed] target_asset: u64,
+        damage: u32,
+    );
+    
+    #[event("lootRolled")]
+    fn loot_rolled_event(
+        &self,
+        #[indexed] battle_id: u64,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] roll: u32,
+        stardust_points: u64,
+    );
+    
+    #[event("consumableUsed")]
+    fn consumable_used_event(
+        &self,
+        #[indexed] battle_id: u64,
+        #[indexed] asset_id: u64,
+        #[indexed] target_asset: u64,
+        healed: u32,
+    );
+    
+    #[event("assetKnockedOut")]
+    fn asset_knocked_out_event(
+        &self,
+        #[indexed] battle_id: u64,
+        #[indexed] asset_id: u64,
+    );
+    
+    #[event("battleCancelled")]
+    fn battle_cancelled_event(
+        &self,
+        #[indexed] attacker: &ManagedAddress,
+        #[indexed] battle_id: u64,
+    );
+    
+    #[event("battleForfeited")]
+    fn battle_forfeited_event(
+        &self,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] forfeiter: &ManagedAddress,
+        #[indexed] battle_id: u64,
+    );
+    
+    #[event("battleTimeoutsSet")]
+    fn battle_timeouts_set_event(&self, #[indexed] acceptance_timeout: u64, turn_timeout: u64);
+    
+    #[event("battleLimitsSet")]
+    fn battle_limits_set_event(&self, #[indexed] rematch_cooldown: u64, daily_experience_cap: u64);
+    
+    #[event("battleRakeSet")]
+    fn battle_rake_set_event(&self, rake_bps: u64);
+    
+    #[event("battleWagerPaid")]
+    fn battle_wager_paid_event(
+        &self,
+        #[indexed] winner: &ManagedAddress,
+        #[indexed] battle_id: u64,
+        payout: &BigUint,
+    );
+    
+    #[event("battleWagerRefunded")]
+    fn battle_wager_refunded_event(
+        &self,
+        #[indexed] attacker: &ManagedAddress,
+        #[indexed] battle_id: u64,
+        amount: &BigUint,
+    );
+    
+    #[event("rankedQueueJoined")]
+    fn ranked_queue_joined_event(&self, #[indexed] player: &ManagedAddress, rating: u32);
+    
+    #[event("rankedQueueLeft")]
+    fn ranked_queue_left_event(&self, #[indexed] player: &ManagedAddress);
+    
+    #[event("rankedMatchFound")]
+    fn ranked_match_found_event(
+        &self,
+        #[indexed] attacker: &ManagedAddress,
+        #[indexed] defender: &ManagedAddress,
+        #[indexed] battle_id: u64,
+    );
+    
     #[event("battleResolved")]
     fn battle_resolved_event(
         &self,
@@ -303,21 +1136,45 @@ pub trait CombatSystem {
 
 // ===== DATA STRUCTURES =====
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct Battle<M: ManagedTypeApi> {
     pub id: u64,
     pub attacker: ManagedAddress<M>,
     pub defender: ManagedAddress<M>,
     pub attacker_assets: ManagedVec<M, u64>,
     pub defender_assets: ManagedVec<M, u64>,
+    pub attacker_combatants: ManagedVec<M, Combatant>,
+    pub defender_combatants: ManagedVec<M, Combatant>,
     pub battle_type: BattleType,
     pub status: BattleStatus,
     pub turn: u32,
     pub created_at: u64,
+    pub last_action_at: u64,
     pub moves: ManagedVec<M, BattleMove<M>>,
+    pub winner: Option<ManagedAddress<M>>,
+    pub commit_reveal: bool,
+    pub wager: Option<EgldOrEsdtTokenPayment<M>>,
+    pub rake_bps: u64,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+/// Combat state of an asset inside a battle, initialized when the battle is accepted.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct Combatant {
+    pub asset_id: u64,
+    pub hp: u32,
+    pub max_hp: u32,
+    pub attack: u32,
+    pub defense: u32,
+    pub speed: u32,
+    pub special: u32,
+    pub energy: u32,
+    pub defending: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct BattleMove<M: ManagedTypeApi> {
     pub turn: u32,
     pub player: ManagedAddress<M>,
@@ -325,9 +1182,22 @@ pub struct BattleMove<M: ManagedTypeApi> {
     pub move_type: MoveType,
     pub target_asset: Option<u64>,
     pub timestamp: u64,
+    pub hits: ManagedVec<M, MoveHit>,
+}
+
+/// Outcome of one hit of a move, with the roll that decided it.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct MoveHit {
+    pub target_asset: u64,
+    pub roll: u32,
+    pub critical: bool,
+    pub dodged: bool,
+    pub damage: u32,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
 pub enum BattleType {
     Casual,
     Ranked,
@@ -335,7 +1205,8 @@ pub enum BattleType {
     Guild,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
 pub enum BattleStatus {
     WaitingForDefender,
     Active,
@@ -343,10 +1214,12 @@ pub enum BattleStatus {
     Cancelled,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
 pub enum MoveType {
     Attack,
     Defend,
     Special,
     Combo,
+    UseItem,
 }
\ No newline at end of file
@@ -0,0 +1,178 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+use crate::asset_storage::GameAsset;
+
+/// Experience divisor of the player curve until a game master configures one
+pub const DEFAULT_PLAYER_EXPERIENCE_DIVISOR: u64 = 100;
+
+/// Experience divisor of the asset curve until a game master configures one
+pub const DEFAULT_ASSET_EXPERIENCE_DIVISOR: u64 = 50;
+
+/// Attack and defense an asset gains per level until a game master configures its stat growth
+pub const DEFAULT_STAT_BONUS_PER_LEVEL: u32 = 5;
+
+/// Asset experience worth one attack and defense point until a game master configures its stat growth
+pub const DEFAULT_EXPERIENCE_PER_STAT_BONUS: u64 = 100;
+
+/// Level Calculation Module for StardustEngine
+/// Progression curves for player and asset levels
+#[multiversx_sc::module]
+pub trait LevelCalculation: access_control::AccessControl {
+    
+    /// Publishes a new version of a progression curve. Stored levels are recalculated
+    /// with the new curve the next time their experience changes.
+    #[endpoint]
+    fn set_level_curve(&self, track: LevelTrack, experience_divisor: u64) -> u32 {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(experience_divisor > 0, "Experience divisor must be positive");
+        
+        let version = self.level_curves(&track).len() as u32 + 1;
+        let curve = LevelCurve {
+            version,
+            experience_divisor,
+            activated_at: self.blockchain().get_block_timestamp(),
+        };
+        self.level_curves(&track).push(&curve);
+        
+        self.level_curve_set_event(&track, version, &curve);
+        version
+    }
+    
+    #[endpoint]
+    fn set_asset_stat_growth(&self, bonus_per_level: u32, experience_per_bonus: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(experience_per_bonus > 0, "Experience per bonus must be positive");
+        
+        let growth = StatGrowth {
+            bonus_per_level,
+            experience_per_bonus,
+        };
+        self.asset_stat_growth().set(&growth);
+        self.asset_stat_growth_set_event(&growth);
+    }
+    
+    fn calculate_level(&self, experience: u64) -> u32 {
+        self.level_on_curve(&LevelTrack::Player, experience)
+    }
+    
+    fn calculate_asset_level(&self, experience: u64) -> u32 {
+        self.level_on_curve(&LevelTrack::Asset, experience)
+    }
+    
+    fn level_on_curve(&self, track: &LevelTrack, experience: u64) -> u32 {
+        let curve = self.get_level_curve(track.clone());
+        ((experience / curve.experience_divisor).isqrt() + 1).try_into().unwrap_or(1u32)
+    }
+    
+    /// Bonus added to the attack and defense of an asset for its level and experience.
+    fn calculate_level_bonus(&self, asset: &GameAsset<Self::Api>) -> u32 {
+        let growth = self.get_asset_stat_growth();
+        let level_bonus = asset.level * growth.bonus_per_level;
+        let exp_bonus = (asset.experience / growth.experience_per_bonus) as u32;
+        
+        level_bonus + exp_bonus
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    /// The active curve of a track, version 0 is the built-in default.
+    #[view]
+    fn get_level_curve(&self, track: LevelTrack) -> LevelCurve {
+        let curves = self.level_curves(&track);
+        if !curves.is_empty() {
+            return curves.get(curves.len());
+        }
+        
+        let experience_divisor = match track {
+            LevelTrack::Player => DEFAULT_PLAYER_EXPERIENCE_DIVISOR,
+            LevelTrack::Asset => DEFAULT_ASSET_EXPERIENCE_DIVISOR,
+        };
+        LevelCurve {
+            version: 0,
+            experience_divisor,
+            activated_at: 0,
+        }
+    }
+    
+    /// Every configured version of a curve, oldest first.
+    #[view]
+    fn get_level_curve_history(&self, track: LevelTrack) -> MultiValueEncoded<LevelCurve> {
+        self.level_curves(&track).iter().collect()
+    }
+    
+    #[view]
+    fn get_asset_stat_growth(&self) -> StatGrowth {
+        if self.asset_stat_growth().is_empty() {
+            StatGrowth {
+                bonus_per_level: DEFAULT_STAT_BONUS_PER_LEVEL,
+                experience_per_bonus: DEFAULT_EXPERIENCE_PER_STAT_BONUS,
+            }
+        } else {
+            self.asset_stat_growth().get()
+        }
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("levelCurves")]
+    fn level_curves(&self, track: &LevelTrack) -> VecMapper<LevelCurve>;
+    
+    #[storage_mapper("assetStatGrowth")]
+    fn asset_stat_growth(&self) -> SingleValueMapper<StatGrowth>;
+    
+    // ===== EVENTS =====
+    
+    #[event("levelCurveSet")]
+    fn level_curve_set_event(
+        &self,
+        #[indexed] track: &LevelTrack,
+        #[indexed] version: u32,
+        curve: &LevelCurve,
+    );
+    
+    #[event("assetStatGrowthSet")]
+    fn asset_stat_growth_set_event(&self, growth: &StatGrowth);
+    
+    #[event("playerLevelUp")]
+    fn player_level_up_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] old_level: u32,
+        new_level: u32,
+    );
+    
+    #[event("assetLevelUp")]
+    fn asset_level_up_event(
+        &self,
+        #[indexed] asset_id: u64,
+        #[indexed] old_level: u32,
+        new_level: u32,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum LevelTrack {
+    Player,
+    Asset,
+}
+
+/// Level is `isqrt(experience / experience_divisor) + 1`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct LevelCurve {
+    pub version: u32,
+    pub experience_divisor: u64,
+    pub activated_at: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct StatGrowth {
+    pub bonus_per_level: u32,
+    pub experience_per_bonus: u64,
+}
@@ -0,0 +1,90 @@
+use multiversx_sc::imports::*;
+
+use crate::access_control::{self, Role};
+use crate::quest_system::{Reward, RewardType};
+use crate::player_storage;
+
+/// Rewards a single level can grant
+pub const MAX_LEVEL_REWARDS: usize = 10;
+
+/// Level Rewards Module for StardustEngine
+/// Reward tables granted once when a player reaches a level
+#[multiversx_sc::module]
+pub trait LevelRewards: access_control::AccessControl + player_storage::PlayerStorage {
+    
+    /// Sets the rewards for reaching a level, an empty list removes them.
+    #[endpoint]
+    fn set_level_rewards(&self, level: u32, rewards: MultiValueEncoded<Reward<Self::Api>>) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(level > 1, "Invalid reward level");
+        require!(rewards.len() <= MAX_LEVEL_REWARDS, "Too many level rewards");
+        
+        let rewards = rewards.to_vec();
+        for reward in rewards.iter() {
+            self.require_valid_reward(&reward);
+        }
+        
+        if rewards.is_empty() {
+            self.level_rewards(level).clear();
+        } else {
+            self.level_rewards(level).set(&rewards);
+        }
+        self.level_rewards_set_event(level, rewards.len() as u32);
+    }
+    
+    fn require_valid_reward(&self, reward: &Reward<Self::Api>) {
+        match reward.reward_type {
+            RewardType::Asset => require!(reward.asset_template.is_some(), "Asset reward needs a template"),
+            RewardType::Title => require!(reward.title.is_some(), "Title reward needs a title"),
+            RewardType::Experience | RewardType::StardustPoints => {},
+        }
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_level_rewards(&self, level: u32) -> ManagedVec<Reward<Self::Api>> {
+        self.level_rewards(level).get()
+    }
+    
+    /// Rewards of the next `count` levels above the player's level, levels without rewards are skipped.
+    #[view]
+    fn get_upcoming_level_rewards(
+        &self,
+        player: ManagedAddress,
+        count: u32,
+    ) -> MultiValueEncoded<MultiValue2<u32, ManagedVec<Reward<Self::Api>>>> {
+        require!(self.players().contains(&player), "Player not registered");
+        let current_level = self.player_stats(&player).get().level;
+        
+        let mut upcoming = MultiValueEncoded::new();
+        for level in current_level + 1..=current_level.saturating_add(count) {
+            if !self.level_rewards(level).is_empty() {
+                upcoming.push((level, self.level_rewards(level).get()).into());
+            }
+        }
+        upcoming
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("levelRewards")]
+    fn level_rewards(&self, level: u32) -> SingleValueMapper<ManagedVec<Reward<Self::Api>>>;
+    
+    /// Highest level whose rewards the player received.
+    #[storage_mapper("levelRewardsGranted")]
+    fn level_rewards_granted(&self, player: &ManagedAddress) -> SingleValueMapper<u32>;
+    
+    // ===== EVENTS =====
+    
+    #[event("levelRewardsSet")]
+    fn level_rewards_set_event(&self, #[indexed] level: u32, reward_count: u32);
+    
+    #[event("levelRewardGranted")]
+    fn level_reward_granted_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] level: u32,
+        reward_count: u32,
+    );
+}
@@ -1,23 +1,77 @@
 #![no_std]
 
-mod combat_system;
-mod quest_system;
+pub mod access_control;
+pub mod asset_evolution;
+pub mod asset_classes;
+pub mod asset_storage;
+pub mod battle_randomness;
+pub mod combat_system;
+pub mod level_calculation;
+pub mod level_rewards;
+pub mod marketplace;
+pub mod mint_pricing;
+pub mod pause;
+pub mod player_storage;
+pub mod progression;
+pub mod quest_system;
+pub mod ranking;
+pub mod seasons;
+pub mod stardust_shop;
+pub mod stardust_token;
+pub mod tournament_system;
+pub mod treasury;
 
-pub use combat_system::*;
-pub use quest_system::*;
+pub use access_control::Role;
+pub use asset_classes::AssetStats;
+pub use asset_storage::{AssetLock, AssetType, GameAsset, Rarity};
+pub use battle_randomness::CombatChances;
+pub use combat_system::{Battle, BattleMove, BattleStatus, BattleType, Combatant, MoveHit, MoveType};
+pub use level_calculation::{LevelCurve, LevelTrack, StatGrowth};
+pub use marketplace::Listing;
+pub use mint_pricing::{MintPrice, MintPriceEntry};
+pub use pause::{PauseState, Subsystem};
+pub use player_storage::PlayerStats;
+pub use quest_system::{
+    AssetTemplate, MissionStatus, MissionTemplate, Objective, ObjectiveType, PlayerMission,
+    RequiredAsset, Reward, RewardType,
+};
+pub use ranking::RatingChange;
+pub use seasons::{LeaderboardEntry, LeaderboardMetric, Season};
+pub use stardust_shop::ShopItem;
+pub use stardust_token::{StardustFlow, StardustSink};
+pub use tournament_system::{Tournament, TournamentMatch, TournamentStatus};
+pub use treasury::{RevenueEntry, RevenueSource};
 
 use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
 
 /// Enhanced Gaming Infrastructure Contract for StardustEngine
 /// The complete blockchain gaming platform with NFT assets, combat, and story progression
 #[multiversx_sc::contract]
 pub trait StardustContracts: 
+    access_control::AccessControl +
+    player_storage::PlayerStorage +
+    asset_classes::AssetClasses +
+    asset_storage::AssetStorage +
+    asset_evolution::AssetEvolution +
+    level_calculation::LevelCalculation +
+    level_rewards::LevelRewards +
+    marketplace::Marketplace +
+    mint_pricing::MintPricing +
+    pause::Pause +
+    progression::Progression +
+    battle_randomness::BattleRandomness +
     combat_system::CombatSystem + 
-    quest_system::QuestSystem 
+    quest_system::QuestSystem +
+    ranking::Ranking +
+    seasons::Seasons +
+    stardust_token::StardustToken +
+    stardust_shop::StardustShop +
+    treasury::Treasury +
+    tournament_system::TournamentSystem
 {
     #[init]
     fn init(&self) {
-        self.next_asset_id().set(1u64);
         self.next_tournament_id().set(1u64);
         self.next_battle_id().set(1u64);
         
@@ -58,7 +112,7 @@ pub trait StardustContracts:
         });
         
         // Initialize Stardust Points
-        self.player_stardust_points(&caller).set(100u64); // Starting bonus
+        self.mint_stardust(&caller, 100u64, StardustFlow::RegistrationBonus); // Starting bonus
         
         self.player_registered_event(&caller);
     }
@@ -79,6 +133,12 @@ pub trait StardustContracts:
         } else {
             let stats = self.player_stats(&player).get();
             let stardust_points = self.player_stardust_points(&player).get();
+            let stardust_token_balance = if self.is_stardust_token_issued() {
+                let token_id = self.stardust_token().get_token_id();
+                self.blockchain().get_esdt_balance(&player, &token_id, 0)
+            } else {
+                BigUint::zero()
+            };
             let titles = self.get_player_titles(player.clone());
             let active_missions = self.get_player_missions(player.clone());
             
@@ -86,6 +146,7 @@ pub trait StardustContracts:
                 address: player,
                 stats,
                 stardust_points,
+                stardust_token_balance,
                 titles,
                 active_missions: active_missions.len() as u32,
                 joined_at: self.blockchain().get_block_timestamp(), // Simplified
@@ -95,14 +156,10 @@ pub trait StardustContracts:
     
     #[endpoint]
     fn update_player_experience(&self, player: ManagedAddress, exp_gained: u64) {
-        self.require_caller_is_admin();
+        self.require_caller_has_role(Role::GameMaster);
         require!(self.players().contains(&player), "Player not registered");
         
-        self.player_stats(&player).update(|stats| {
-            stats.experience += exp_gained;
-            stats.level = self.calculate_level(stats.experience);
-        });
-        
+        self.add_player_experience(&player, exp_gained);
         self.experience_gained_event(&player, exp_gained);
     }
 
@@ -110,182 +167,156 @@ pub trait StardustContracts:
     
     #[endpoint]
     #[payable("EGLD")]
-    fn mint_game_asset(
+    fn issue_game_asset_collection(
         &self,
-        asset_type: AssetType,
-        rarity: Rarity,
-        name: ManagedBuffer,
-        description: ManagedBuffer,
-    ) -> u64 {
-        let payment = self.call_value().egld_value().clone_value();
-        let mint_cost = self.get_mint_cost(&rarity);
-        require!(payment >= mint_cost, "Insufficient payment for minting");
+        token_display_name: ManagedBuffer,
+        token_ticker: ManagedBuffer,
+    ) {
+        self.require_caller_is_admin();
         
+        let issue_cost = self.call_value().egld().clone_value();
         let caller = self.blockchain().get_caller();
-        require!(self.players().contains(&caller), "Player not registered");
-        
-        let asset_id = self.next_asset_id().get();
-        self.next_asset_id().set(asset_id + 1);
-        
-        let asset = GameAsset {
-            id: asset_id,
-            owner: caller.clone(),
-            asset_type: asset_type.clone(),
-            rarity: rarity.clone(),
-            name: name.clone(),
-            description,
-            created_at: self.blockchain().get_block_timestamp(),
-            level: 1u32,
-            experience: 0u64,
-        };
-        
-        self.game_assets().insert(asset_id);
-        self.asset_owner(asset_id).set(caller.clone());
-        self.asset_metadata(asset_id).set(asset);
-        
-        // Update player stats
-        self.player_stats(&caller).update(|stats| {
-            stats.assets_owned += 1;
-        });
         
-        self.asset_minted_event(&caller, asset_id, &name, &asset_type, &rarity);
-        asset_id
+        self.game_asset_token().issue_and_set_all_roles(
+            EsdtTokenType::NonFungible,
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0usize,
+            Some(self.callbacks().game_asset_issue_callback(&caller)),
+        );
+    }
+    
+    #[callback]
+    fn game_asset_issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.game_asset_token().set_token_id(token_id.clone());
+                self.game_asset_collection_issued_event(&token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                // Issuance failed, give the issue cost back to the admin
+                self.game_asset_token().clear();
+                let returned = self.call_value().egld_direct_non_strict();
+                if *returned > 0u32 {
+                    self.send().direct_egld(caller, &returned);
+                }
+            },
+        }
     }
     
+    // ===== STARDUST TOKEN =====
+    
+    /// Issues the Stardust token with local mint and burn roles for the contract.
     #[endpoint]
-    fn transfer_asset(&self, asset_id: u64, to: ManagedAddress) {
-        let caller = self.blockchain().get_caller();
-        require!(self.game_assets().contains(&asset_id), "Asset does not exist");
-        require!(self.asset_owner(asset_id).get() == caller, "Not asset owner");
-        require!(self.players().contains(&to), "Recipient not registered");
-        
-        self.asset_owner(asset_id).set(to.clone());
-        self.asset_metadata(asset_id).update(|asset| {
-            asset.owner = to.clone();
-        });
+    #[payable("EGLD")]
+    fn issue_stardust_token(&self, token_display_name: ManagedBuffer, token_ticker: ManagedBuffer) {
+        self.require_caller_is_admin();
+        require!(self.stardust_token().is_empty(), "Stardust token already issued");
         
-        // Update stats
-        self.player_stats(&caller).update(|stats| {
-            stats.assets_owned -= 1;
-        });
-        self.player_stats(&to).update(|stats| {
-            stats.assets_owned += 1;
-        });
+        let issue_cost = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
         
-        self.asset_transferred_event(&caller, &to, asset_id);
-    }
-    
-    #[view]
-    fn get_asset(&self, asset_id: u64) -> OptionalValue<GameAsset<Self::Api>> {
-        if !self.game_assets().contains(&asset_id) {
-            OptionalValue::None
-        } else {
-            OptionalValue::Some(self.asset_metadata(asset_id).get())
-        }
+        self.stardust_token().issue_and_set_all_roles(
+            issue_cost,
+            token_display_name,
+            token_ticker,
+            0usize,
+            Some(self.callbacks().stardust_token_issue_callback(&caller)),
+        );
     }
     
-    #[view]
-    fn get_player_assets(&self, player: ManagedAddress) -> ManagedVec<GameAsset<Self::Api>> {
-        let mut assets = ManagedVec::new();
-        for asset_id in self.game_assets().iter() {
-            if self.asset_owner(asset_id).get() == player {
-                assets.push(self.asset_metadata(asset_id).get());
-            }
+    #[callback]
+    fn stardust_token_issue_callback(
+        &self,
+        caller: &ManagedAddress,
+        #[call_result] result: ManagedAsyncCallResult<TokenIdentifier>,
+    ) {
+        match result {
+            ManagedAsyncCallResult::Ok(token_id) => {
+                self.stardust_token().set_token_id(token_id.clone());
+                self.stardust_token_issued_event(&token_id);
+            },
+            ManagedAsyncCallResult::Err(_) => {
+                // Issuance failed, give the issue cost back to the admin
+                self.stardust_token().clear();
+                let returned = self.call_value().egld_direct_non_strict();
+                if *returned > 0u32 {
+                    self.send().direct_egld(caller, &returned);
+                }
+            },
         }
-        assets
     }
-
-    // ===== TOURNAMENT SYSTEM =====
     
+    /// Mints a new game asset, paid in the token configured for its type and rarity.
     #[endpoint]
-    #[payable("EGLD")]
-    fn create_tournament(
+    #[payable("*")]
+    fn mint_game_asset(
         &self,
+        asset_type: AssetType,
+        rarity: Rarity,
         name: ManagedBuffer,
-        entry_fee: BigUint,
-        max_participants: u32,
-        start_time: u64,
+        description: ManagedBuffer,
     ) -> u64 {
-        let prize_pool = self.call_value().egld_value().clone_value();
-        require!(prize_pool > BigUint::zero(), "Prize pool required");
+        self.require_not_paused(Subsystem::Minting);
+        let payment = self.call_value().egld_or_single_esdt();
+        let mint_price = self.get_mint_price(&asset_type, &rarity);
+        require!(payment.token_identifier == mint_price.token_id, "Invalid payment token");
+        require!(payment.amount >= mint_price.amount, "Insufficient payment for minting");
         
-        let tournament_id = self.next_tournament_id().get();
-        self.next_tournament_id().set(tournament_id + 1);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
         
-        let tournament = Tournament {
-            id: tournament_id,
-            name: name.clone(),
-            organizer: self.blockchain().get_caller(),
-            entry_fee,
-            prize_pool: prize_pool.clone(),
-            max_participants,
-            current_participants: 0u32,
-            start_time,
-            status: TournamentStatus::Registration,
-            participants: ManagedVec::new(),
-        };
+        let asset_id = self.create_game_asset(&caller, asset_type.clone(), rarity.clone(), name.clone(), description);
         
-        self.tournaments().insert(tournament_id);
-        self.tournament_data(tournament_id).set(tournament);
+        // Only the mint price is kept, any surplus goes back to the caller
+        let surplus = &payment.amount - &mint_price.amount;
+        if surplus > 0u32 {
+            self.send().direct(&caller, &payment.token_identifier, payment.token_nonce, &surplus);
+        }
+        self.record_revenue(RevenueSource::Mint, &mint_price.token_id, &mint_price.amount);
         
-        self.tournament_created_event(tournament_id, &name, &prize_pool);
-        tournament_id
+        self.asset_minted_event(&caller, asset_id, &asset_type, &rarity, &name, &mint_price.token_id, &mint_price.amount);
+        asset_id
     }
     
+    /// Transfers a game asset NFT to another registered player.
+    /// The asset is sent along with the call and forwarded to the recipient.
     #[endpoint]
-    #[payable("EGLD")]
-    fn join_tournament(&self, tournament_id: u64) {
-        let payment = self.call_value().egld_value().clone_value();
-        let caller = self.blockchain().get_caller();
+    #[payable("*")]
+    fn transfer_asset(&self, to: ManagedAddress) {
+        self.require_not_paused(Subsystem::Transfers);
+        let payment = self.call_value().single_esdt().clone();
+        self.game_asset_token().require_same_token(&payment.token_identifier);
         
-        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        let caller = self.blockchain().get_caller();
+        let asset_id = payment.token_nonce;
+        require!(self.game_assets().contains(&asset_id), "Asset does not exist");
         require!(self.players().contains(&caller), "Player not registered");
+        require!(self.players().contains(&to), "Recipient not registered");
         
-        let mut tournament = self.tournament_data(tournament_id).get();
-        require!(tournament.status == TournamentStatus::Registration, "Registration closed");
-        require!(tournament.current_participants < tournament.max_participants, "Tournament full");
-        require!(payment >= tournament.entry_fee, "Insufficient entry fee");
-        require!(!tournament.participants.contains(&caller), "Already registered");
-        
-        tournament.participants.push(caller.clone());
-        tournament.current_participants += 1;
-        tournament.prize_pool += payment.clone();
+        self.set_asset_owner(asset_id, &caller);
+        self.set_asset_owner(asset_id, &to);
         
-        self.tournament_data(tournament_id).set(tournament);
+        self.send().direct_esdt(&to, &payment.token_identifier, asset_id, &payment.amount);
         
-        self.tournament_joined_event(&caller, tournament_id, &payment);
+        self.asset_transferred_event(&caller, &to, asset_id);
     }
     
     #[view]
-    fn get_tournament(&self, tournament_id: u64) -> OptionalValue<Tournament<Self::Api>> {
-        if !self.tournaments().contains(&tournament_id) {
+    fn get_asset(&self, asset_id: u64) -> OptionalValue<GameAsset<Self::Api>> {
+        if !self.game_assets().contains(&asset_id) {
             OptionalValue::None
         } else {
-            OptionalValue::Some(self.tournament_data(tournament_id).get())
-        }
-    }
-
-    // ===== HELPER FUNCTIONS =====
-    
-    fn calculate_level(&self, experience: u64) -> u32 {
-        // Enhanced level calculation for better progression
-        ((experience / 100).integer_sqrt() + 1).try_into().unwrap_or(1u32)
-    }
-    
-    fn get_mint_cost(&self, rarity: &Rarity) -> BigUint {
-        match rarity {
-            Rarity::Common => BigUint::from(1000000000000000000u64), // 1 EGLD
-            Rarity::Rare => BigUint::from(2000000000000000000u64),   // 2 EGLD
-            Rarity::Epic => BigUint::from(5000000000000000000u64),   // 5 EGLD
-            Rarity::Legendary => BigUint::from(10000000000000000000u64), // 10 EGLD
+            OptionalValue::Some(self.asset_metadata(asset_id).get())
         }
     }
     
-    fn require_caller_is_admin(&self) {
-        let caller = self.blockchain().get_caller();
-        let owner = self.blockchain().get_owner_address();
-        require!(caller == owner, "Only admin can call this function");
-    }
+    // ===== HELPER FUNCTIONS =====
     
     #[view]
     fn get_player_titles(&self, player: ManagedAddress) -> ManagedVec<ManagedBuffer> {
@@ -310,11 +341,6 @@ pub trait StardustContracts:
         self.game_assets().len()
     }
     
-    #[view]
-    fn get_total_tournaments(&self) -> usize {
-        self.tournaments().len()
-    }
-    
     #[view]
     fn is_player_registered(&self, player: &ManagedAddress) -> bool {
         self.players().contains(player)
@@ -331,49 +357,16 @@ pub trait StardustContracts:
         }
     }
 
-    // ===== STORAGE =====
-    
-    #[storage_mapper("players")]
-    fn players(&self) -> SetMapper<ManagedAddress>;
-    
-    #[storage_mapper("playerStats")]
-    fn player_stats(&self, player: &ManagedAddress) -> SingleValueMapper<PlayerStats<Self::Api>>;
-    
-    #[storage_mapper("gameAssets")]
-    fn game_assets(&self) -> UnorderedSetMapper<u64>;
-    
-    #[storage_mapper("assetOwner")]
-    fn asset_owner(&self, asset_id: u64) -> SingleValueMapper<ManagedAddress>;
-    
-    #[storage_mapper("assetMetadata")]
-    fn asset_metadata(&self, asset_id: u64) -> SingleValueMapper<GameAsset<Self::Api>>;
-    
-    #[storage_mapper("nextAssetId")]
-    fn next_asset_id(&self) -> SingleValueMapper<u64>;
-    
-    #[storage_mapper("tournaments")]
-    fn tournaments(&self) -> UnorderedSetMapper<u64>;
-    
-    #[storage_mapper("tournamentData")]
-    fn tournament_data(&self, tournament_id: u64) -> SingleValueMapper<Tournament<Self::Api>>;
-    
-    #[storage_mapper("nextTournamentId")]
-    fn next_tournament_id(&self) -> SingleValueMapper<u64>;
-
     // ===== EVENTS =====
     
     #[event("playerRegistered")]
     fn player_registered_event(&self, #[indexed] player: &ManagedAddress);
     
-    #[event("assetMinted")]
-    fn asset_minted_event(
-        &self,
-        #[indexed] player: &ManagedAddress,
-        #[indexed] asset_id: u64,
-        name: &ManagedBuffer,
-        asset_type: &AssetType,
-        rarity: &Rarity,
-    );
+    #[event("gameAssetCollectionIssued")]
+    fn game_asset_collection_issued_event(&self, #[indexed] token_id: &TokenIdentifier);
+    
+    #[event("stardustTokenIssued")]
+    fn stardust_token_issued_event(&self, #[indexed] token_id: &TokenIdentifier);
     
     #[event("assetTransferred")]
     fn asset_transferred_event(
@@ -389,74 +382,26 @@ pub trait StardustContracts:
         #[indexed] player: &ManagedAddress,
         exp_gained: u64,
     );
-    
-    #[event("tournamentCreated")]
-    fn tournament_created_event(
-        &self,
-        #[indexed] tournament_id: u64,
-        name: &ManagedBuffer,
-        prize_pool: &BigUint,
-    );
-    
-    #[event("tournamentJoined")]
-    fn tournament_joined_event(
-        &self,
-        #[indexed] player: &ManagedAddress,
-        #[indexed] tournament_id: u64,
-        entry_fee: &BigUint,
-    );
 }
 
 // ===== DATA STRUCTURES =====
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
-pub struct PlayerStats<M: ManagedTypeApi> {
-    pub level: u32,
-    pub experience: u64,
-    pub games_played: u32,
-    pub games_won: u32,
-    pub assets_owned: u32,
-    pub achievements: ManagedVec<M, ManagedBuffer<M>>,
-}
-
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct PlayerProfile<M: ManagedTypeApi> {
     pub address: ManagedAddress<M>,
     pub stats: PlayerStats<M>,
+    /// Stored Stardust Points, zero once migrated to the token
     pub stardust_points: u64,
+    /// Stardust token balance once the token is issued, only readable for accounts on the contract's shard
+    pub stardust_token_balance: BigUint<M>,
     pub titles: ManagedVec<M, ManagedBuffer<M>>,
     pub active_missions: u32,
     pub joined_at: u64,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
-pub struct GameAsset<M: ManagedTypeApi> {
-    pub id: u64,
-    pub owner: ManagedAddress<M>,
-    pub asset_type: AssetType,
-    pub rarity: Rarity,
-    pub name: ManagedBuffer<M>,
-    pub description: ManagedBuffer<M>,
-    pub created_at: u64,
-    pub level: u32,
-    pub experience: u64,
-}
-
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
-pub struct Tournament<M: ManagedTypeApi> {
-    pub id: u64,
-    pub name: ManagedBuffer<M>,
-    pub organizer: ManagedAddress<M>,
-    pub entry_fee: BigUint<M>,
-    pub prize_pool: BigUint<M>,
-    pub max_participants: u32,
-    pub current_participants: u32,
-    pub start_time: u64,
-    pub status: TournamentStatus,
-    pub participants: ManagedVec<M, ManagedAddress<M>>,
-}
-
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct PlatformStats {
     pub total_players: u64,
     pub total_assets: u64,
@@ -465,28 +410,3 @@ pub struct PlatformStats {
     pub total_missions: u64,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
-pub enum AssetType {
-    Weapon,
-    Character,
-    Skin,
-    Consumable,
-    Vehicle,
-    Structure,
-}
-
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub enum Rarity {
-    Common,
-    Rare,
-    Epic,
-    Legendary,
-}
-
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
-pub enum TournamentStatus {
-    Registration,
-    Active,
-    Completed,
-    Cancelled,
-}
\ No newline at end of file
@@ -0,0 +1,317 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::Role;
+use crate::asset_storage::{self, AssetLock, AssetType, Rarity};
+use crate::pause::{self, Subsystem};
+use crate::tournament_system::TOTAL_BASIS_POINTS;
+use crate::treasury::{self, RevenueSource};
+use crate::{access_control, asset_classes, player_storage};
+
+/// Highest platform fee the marketplace can take from a sale, in basis points
+pub const MAX_MARKETPLACE_FEE_BPS: u64 = 1_000;
+
+/// Highest royalty the creator of an asset can receive from a sale, in basis points
+pub const MAX_CREATOR_ROYALTY_BPS: u64 = 1_000;
+
+/// Marketplace Module for StardustEngine
+/// Fixed price listings of game assets held by the contract until they sell or are cancelled
+#[multiversx_sc::module]
+pub trait Marketplace:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + pause::Pause
+    + treasury::Treasury
+{
+    
+    // ===== LISTINGS =====
+    
+    /// Lists the asset NFT sent along with the call at a fixed price in EGLD or a fungible ESDT.
+    /// The asset stays locked in the contract until it is bought or the listing is cancelled.
+    /// The marketplace fees are fixed for the listing when it is created.
+    #[endpoint]
+    #[payable("*")]
+    fn list_asset(&self, token_id: EgldOrEsdtTokenIdentifier, price: BigUint) -> u64 {
+        self.require_not_paused(Subsystem::Marketplace);
+        let payment = self.call_value().single_esdt().clone();
+        self.game_asset_token().require_same_token(&payment.token_identifier);
+        
+        let seller = self.blockchain().get_caller();
+        require!(self.players().contains(&seller), "Player not registered");
+        self.require_valid_price(&token_id, &price);
+        
+        let asset_id = payment.token_nonce;
+        self.lock_asset(asset_id, AssetLock::Listed);
+        self.set_asset_owner(asset_id, &seller);
+        
+        let asset = self.asset_metadata(asset_id).get();
+        let listing_id = self.next_listing_id().get() + 1;
+        self.next_listing_id().set(listing_id);
+        self.listings().insert(listing_id);
+        self.listing(listing_id).set(Listing {
+            id: listing_id,
+            asset_id,
+            seller: seller.clone(),
+            asset_type: asset.asset_type,
+            rarity: asset.rarity,
+            token_id: token_id.clone(),
+            price: price.clone(),
+            listed_at: self.blockchain().get_block_timestamp(),
+            platform_fee_bps: self.marketplace_fee_bps().get(),
+            creator_royalty_bps: self.creator_royalty_bps().get(),
+        });
+        
+        self.asset_listed_event(&seller, listing_id, asset_id, &token_id, &price);
+        listing_id
+    }
+    
+    #[endpoint]
+    fn update_listing(&self, listing_id: u64, token_id: EgldOrEsdtTokenIdentifier, price: BigUint) {
+        self.require_not_paused(Subsystem::Marketplace);
+        let mut listing = self.require_seller_listing(listing_id);
+        self.require_valid_price(&token_id, &price);
+        
+        listing.token_id = token_id.clone();
+        listing.price = price.clone();
+        self.listing(listing_id).set(&listing);
+        
+        self.listing_updated_event(&listing.seller, listing_id, &token_id, &price);
+    }
+    
+    /// Removes a listing and returns the asset to the seller, also while the marketplace is paused.
+    #[endpoint]
+    fn cancel_listing(&self, listing_id: u64) {
+        let listing = self.require_seller_listing(listing_id);
+        
+        self.remove_listing(&listing);
+        let asset_token_id = self.game_asset_token().get_token_id();
+        self.send().direct_esdt(&listing.seller, &asset_token_id, listing.asset_id, &BigUint::from(1u32));
+        
+        self.listing_cancelled_event(&listing.seller, listing_id, listing.asset_id);
+    }
+    
+    /// Buys a listed asset for exactly its price. The platform fee goes to the treasury,
+    /// the creator royalty to the configured royalty recipient and the rest to the seller.
+    #[endpoint]
+    #[payable("*")]
+    fn buy_asset(&self, listing_id: u64) {
+        self.require_not_paused(Subsystem::Marketplace);
+        let payment = self.call_value().egld_or_single_esdt();
+        require!(payment.token_nonce == 0, "Invalid payment token");
+        let buyer = self.blockchain().get_caller();
+        require!(self.players().contains(&buyer), "Player not registered");
+        require!(self.listings().contains(&listing_id), "Listing not found");
+        
+        let listing = self.listing(listing_id).get();
+        require!(listing.seller != buyer, "Cannot buy your own listing");
+        require!(payment.token_identifier == listing.token_id, "Invalid payment token");
+        require!(payment.amount == listing.price, "Wrong payment amount");
+        
+        let platform_fee = &listing.price * listing.platform_fee_bps / TOTAL_BASIS_POINTS;
+        self.record_revenue(RevenueSource::MarketplaceFee, &listing.token_id, &platform_fee);
+        
+        let mut seller_payout = &listing.price - &platform_fee;
+        let royalty = &listing.price * listing.creator_royalty_bps / TOTAL_BASIS_POINTS;
+        if royalty > 0u32 {
+            let recipient = self.creator_royalty_recipient().get();
+            seller_payout -= &royalty;
+            self.send().direct(&recipient, &listing.token_id, 0, &royalty);
+            self.creator_royalty_paid_event(&recipient, listing.asset_id, &royalty);
+        }
+        if seller_payout > 0u32 {
+            self.send().direct(&listing.seller, &listing.token_id, 0, &seller_payout);
+        }
+        
+        self.remove_listing(&listing);
+        self.set_asset_owner(listing.asset_id, &buyer);
+        let asset_token_id = self.game_asset_token().get_token_id();
+        self.send().direct_esdt(&buyer, &asset_token_id, listing.asset_id, &BigUint::from(1u32));
+        
+        self.asset_sold_event(&listing.seller, &buyer, listing_id, listing.asset_id, &listing.price);
+    }
+    
+    #[endpoint]
+    fn set_marketplace_fees(&self, platform_fee_bps: u64, creator_royalty_bps: u64) {
+        self.require_caller_has_role(Role::Treasurer);
+        require!(platform_fee_bps <= MAX_MARKETPLACE_FEE_BPS, "Platform fee too high");
+        require!(creator_royalty_bps <= MAX_CREATOR_ROYALTY_BPS, "Creator royalty too high");
+        require!(
+            creator_royalty_bps == 0 || !self.creator_royalty_recipient().is_empty(),
+            "Creator royalty recipient not set"
+        );
+        
+        self.marketplace_fee_bps().set(platform_fee_bps);
+        self.creator_royalty_bps().set(creator_royalty_bps);
+        self.marketplace_fees_set_event(platform_fee_bps, creator_royalty_bps);
+    }
+    
+    /// Sets the studio or treasury address the creator royalty of every sale is paid to.
+    /// Assets are minted by the contract, so there is no individual creator to pay.
+    #[endpoint]
+    fn set_creator_royalty_recipient(&self, recipient: ManagedAddress) {
+        self.require_caller_has_role(Role::Treasurer);
+        
+        self.creator_royalty_recipient().set(&recipient);
+        self.creator_royalty_recipient_set_event(&recipient);
+    }
+    
+    fn require_seller_listing(&self, listing_id: u64) -> Listing<Self::Api> {
+        require!(self.listings().contains(&listing_id), "Listing not found");
+        let listing = self.listing(listing_id).get();
+        require!(listing.seller == self.blockchain().get_caller(), "Only the seller can change the listing");
+        listing
+    }
+    
+    /// Sales are paid out with nonce 0, so prices have to be in EGLD or a fungible ESDT.
+    /// The contract cannot look up the type of other tokens, the game asset collection is rejected here
+    /// and any other non-fungible payment when buying.
+    fn require_valid_price(&self, token_id: &EgldOrEsdtTokenIdentifier, price: &BigUint) {
+        require!(token_id.is_valid(), "Invalid payment token");
+        let asset_token_id = EgldOrEsdtTokenIdentifier::esdt(self.game_asset_token().get_token_id());
+        require!(*token_id != asset_token_id, "Invalid payment token");
+        require!(*price > 0u32, "Price must be positive");
+    }
+    
+    fn remove_listing(&self, listing: &Listing<Self::Api>) {
+        self.listings().remove(&listing.id);
+        self.listing(listing.id).clear();
+        self.unlock_asset(listing.asset_id);
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_listing(&self, listing_id: u64) -> OptionalValue<Listing<Self::Api>> {
+        if self.listings().contains(&listing_id) {
+            OptionalValue::Some(self.listing(listing_id).get())
+        } else {
+            OptionalValue::None
+        }
+    }
+    
+    /// Active listings, oldest first, optionally filtered by asset type and rarity.
+    /// `from` and `count` page through the filtered listings.
+    #[view]
+    fn get_listings(
+        &self,
+        asset_type: Option<AssetType>,
+        rarity: Option<Rarity>,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<Listing<Self::Api>> {
+        self.listings()
+            .iter()
+            .map(|listing_id| self.listing(listing_id).get())
+            .filter(|listing| asset_type.as_ref().is_none_or(|asset_type| listing.asset_type == *asset_type))
+            .filter(|listing| rarity.as_ref().is_none_or(|rarity| listing.rarity == *rarity))
+            .skip(from)
+            .take(count)
+            .collect()
+    }
+    
+    #[view]
+    fn get_marketplace_fees(&self) -> MultiValue2<u64, u64> {
+        (self.marketplace_fee_bps().get(), self.creator_royalty_bps().get()).into()
+    }
+    
+    #[view]
+    fn get_creator_royalty_recipient(&self) -> OptionalValue<ManagedAddress> {
+        if self.creator_royalty_recipient().is_empty() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.creator_royalty_recipient().get())
+        }
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("listings")]
+    fn listings(&self) -> SetMapper<u64>;
+    
+    #[storage_mapper("listing")]
+    fn listing(&self, listing_id: u64) -> SingleValueMapper<Listing<Self::Api>>;
+    
+    #[storage_mapper("nextListingId")]
+    fn next_listing_id(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("marketplaceFeeBps")]
+    fn marketplace_fee_bps(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("creatorRoyaltyBps")]
+    fn creator_royalty_bps(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("creatorRoyaltyRecipient")]
+    fn creator_royalty_recipient(&self) -> SingleValueMapper<ManagedAddress>;
+    
+    // ===== EVENTS =====
+    
+    #[event("assetListed")]
+    fn asset_listed_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] listing_id: u64,
+        #[indexed] asset_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        price: &BigUint,
+    );
+    
+    #[event("listingUpdated")]
+    fn listing_updated_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] listing_id: u64,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        price: &BigUint,
+    );
+    
+    #[event("listingCancelled")]
+    fn listing_cancelled_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] listing_id: u64,
+        asset_id: u64,
+    );
+    
+    #[event("assetSold")]
+    fn asset_sold_event(
+        &self,
+        #[indexed] seller: &ManagedAddress,
+        #[indexed] buyer: &ManagedAddress,
+        #[indexed] listing_id: u64,
+        #[indexed] asset_id: u64,
+        price: &BigUint,
+    );
+    
+    #[event("creatorRoyaltyPaid")]
+    fn creator_royalty_paid_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] asset_id: u64,
+        amount: &BigUint,
+    );
+    
+    #[event("marketplaceFeesSet")]
+    fn marketplace_fees_set_event(&self, #[indexed] platform_fee_bps: u64, creator_royalty_bps: u64);
+    
+    #[event("creatorRoyaltyRecipientSet")]
+    fn creator_royalty_recipient_set_event(&self, recipient: &ManagedAddress);
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Listing<M: ManagedTypeApi> {
+    pub id: u64,
+    pub asset_id: u64,
+    pub seller: ManagedAddress<M>,
+    pub asset_type: AssetType,
+    pub rarity: Rarity,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub price: BigUint<M>,
+    pub listed_at: u64,
+    pub platform_fee_bps: u64,
+    pub creator_royalty_bps: u64,
+}
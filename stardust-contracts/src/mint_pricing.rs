@@ -0,0 +1,105 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control;
+use crate::asset_storage::{AssetType, Rarity};
+
+/// One EGLD in its smallest denomination
+pub const ONE_EGLD: u64 = 1_000_000_000_000_000_000;
+
+/// Mint Pricing Module for StardustEngine
+/// Admin-managed mint price table keyed by asset type and rarity, payable in EGLD or any ESDT
+#[multiversx_sc::module]
+pub trait MintPricing: access_control::AccessControl {
+    
+    #[endpoint]
+    fn set_mint_price(
+        &self,
+        asset_type: AssetType,
+        rarity: Rarity,
+        token_id: EgldOrEsdtTokenIdentifier,
+        amount: BigUint,
+    ) {
+        self.require_caller_is_admin();
+        require!(token_id.is_valid(), "Invalid payment token");
+        require!(amount > 0u32, "Mint price must be positive");
+        
+        let price = MintPrice { token_id, amount };
+        self.mint_price(&asset_type, &rarity).set(&price);
+        
+        self.mint_price_set_event(&asset_type, &rarity, &price);
+    }
+    
+    /// Falls back to the default EGLD price of the rarity while no price is configured.
+    fn get_mint_price(&self, asset_type: &AssetType, rarity: &Rarity) -> MintPrice<Self::Api> {
+        let price_mapper = self.mint_price(asset_type, rarity);
+        if !price_mapper.is_empty() {
+            return price_mapper.get();
+        }
+        
+        MintPrice {
+            token_id: EgldOrEsdtTokenIdentifier::egld(),
+            amount: self.get_default_mint_cost(rarity),
+        }
+    }
+    
+    /// Whole EGLD are scaled up as a BigUint, 10 EGLD in wei does not fit the VM's i64 conversion.
+    fn get_default_mint_cost(&self, rarity: &Rarity) -> BigUint {
+        let egld = match rarity {
+            Rarity::Common => 1u64,
+            Rarity::Rare => 2u64,
+            Rarity::Epic => 5u64,
+            Rarity::Legendary => 10u64,
+        };
+        BigUint::from(ONE_EGLD) * egld
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_mint_price_table(&self) -> MultiValueEncoded<MintPriceEntry<Self::Api>> {
+        let mut table = MultiValueEncoded::new();
+        for asset_type in AssetType::ALL.iter() {
+            for rarity in Rarity::ALL.iter() {
+                table.push(MintPriceEntry {
+                    asset_type: asset_type.clone(),
+                    rarity: rarity.clone(),
+                    price: self.get_mint_price(asset_type, rarity),
+                });
+            }
+        }
+        table
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("mintPrice")]
+    fn mint_price(&self, asset_type: &AssetType, rarity: &Rarity) -> SingleValueMapper<MintPrice<Self::Api>>;
+    
+    // ===== EVENTS =====
+    
+    #[event("mintPriceSet")]
+    fn mint_price_set_event(
+        &self,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] rarity: &Rarity,
+        price: &MintPrice<Self::Api>,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MintPrice<M: ManagedTypeApi> {
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub amount: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct MintPriceEntry<M: ManagedTypeApi> {
+    pub asset_type: AssetType,
+    pub rarity: Rarity,
+    pub price: MintPrice<M>,
+}
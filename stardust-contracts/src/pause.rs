@@ -0,0 +1,133 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control;
+
+/// Pause Module for StardustEngine
+/// Global pause and per-subsystem circuit breakers for emergency response
+#[multiversx_sc::module]
+pub trait Pause: access_control::AccessControl {
+    
+    #[endpoint(pause)]
+    fn pause_endpoint(&self) {
+        self.require_caller_is_admin();
+        self.global_paused().set(true);
+        self.global_pause_changed_event(true);
+    }
+    
+    #[endpoint(unpause)]
+    fn unpause_endpoint(&self) {
+        self.require_caller_is_admin();
+        if self.global_paused().take() {
+            self.global_resumed_at().set(self.blockchain().get_block_timestamp());
+        }
+        self.global_pause_changed_event(false);
+    }
+    
+    #[endpoint]
+    fn pause_subsystem(&self, subsystem: Subsystem) {
+        self.require_caller_is_admin();
+        self.subsystem_paused(&subsystem).set(true);
+        self.subsystem_pause_changed_event(&subsystem, true);
+    }
+    
+    #[endpoint]
+    fn unpause_subsystem(&self, subsystem: Subsystem) {
+        self.require_caller_is_admin();
+        if self.subsystem_paused(&subsystem).take() {
+            self.subsystem_resumed_at(&subsystem).set(self.blockchain().get_block_timestamp());
+        }
+        self.subsystem_pause_changed_event(&subsystem, false);
+    }
+    
+    /// Fails while the whole contract or the given subsystem is paused.
+    fn require_not_paused(&self, subsystem: Subsystem) {
+        require!(!self.global_paused().get(), "Contract is paused");
+        require!(!self.subsystem_paused(&subsystem).get(), "Subsystem is paused");
+    }
+    
+    /// When the subsystem last came back from a global or subsystem pause, 0 if it never was paused.
+    /// Deadlines count from here at the earliest, so time spent paused is not held against players.
+    fn get_resumed_at(&self, subsystem: &Subsystem) -> u64 {
+        core::cmp::max(self.global_resumed_at().get(), self.subsystem_resumed_at(subsystem).get())
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_pause_state(&self) -> PauseState<Self::Api> {
+        let mut paused_subsystems = ManagedVec::new();
+        for subsystem in Subsystem::ALL.iter() {
+            if self.subsystem_paused(subsystem).get() {
+                paused_subsystems.push(subsystem.clone());
+            }
+        }
+        
+        PauseState {
+            global_paused: self.global_paused().get(),
+            paused_subsystems,
+        }
+    }
+    
+    #[view]
+    fn is_subsystem_paused(&self, subsystem: Subsystem) -> bool {
+        self.global_paused().get() || self.subsystem_paused(&subsystem).get()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("globalPaused")]
+    fn global_paused(&self) -> SingleValueMapper<bool>;
+    
+    #[storage_mapper("subsystemPaused")]
+    fn subsystem_paused(&self, subsystem: &Subsystem) -> SingleValueMapper<bool>;
+    
+    #[storage_mapper("globalResumedAt")]
+    fn global_resumed_at(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("subsystemResumedAt")]
+    fn subsystem_resumed_at(&self, subsystem: &Subsystem) -> SingleValueMapper<u64>;
+    
+    // ===== EVENTS =====
+    
+    #[event("globalPauseChanged")]
+    fn global_pause_changed_event(&self, paused: bool);
+    
+    #[event("subsystemPauseChanged")]
+    fn subsystem_pause_changed_event(
+        &self,
+        #[indexed] subsystem: &Subsystem,
+        paused: bool,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
+pub enum Subsystem {
+    Minting,
+    Transfers,
+    Battles,
+    Quests,
+    Tournaments,
+    Marketplace,
+}
+
+impl Subsystem {
+    pub const ALL: [Subsystem; 6] = [
+        Subsystem::Minting,
+        Subsystem::Transfers,
+        Subsystem::Battles,
+        Subsystem::Quests,
+        Subsystem::Tournaments,
+        Subsystem::Marketplace,
+    ];
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PauseState<M: ManagedTypeApi> {
+    pub global_paused: bool,
+    pub paused_subsystems: ManagedVec<M, Subsystem>,
+}
@@ -0,0 +1,35 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+/// Player Storage Module for StardustEngine
+/// Holds the player registry and per-player statistics shared by all game systems
+#[multiversx_sc::module]
+pub trait PlayerStorage {
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("players")]
+    fn players(&self) -> SetMapper<ManagedAddress>;
+    
+    #[storage_mapper("playerStats")]
+    fn player_stats(&self, player: &ManagedAddress) -> SingleValueMapper<PlayerStats<Self::Api>>;
+    
+    #[storage_mapper("playerStardustPoints")]
+    fn player_stardust_points(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("playerTitles")]
+    fn player_titles(&self, player: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct PlayerStats<M: ManagedTypeApi> {
+    pub level: u32,
+    pub experience: u64,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub assets_owned: u32,
+    pub achievements: ManagedVec<M, ManagedBuffer<M>>,
+}
@@ -0,0 +1,119 @@
+use multiversx_sc::imports::*;
+
+use crate::access_control::{self, Role};
+use crate::quest_system::{AssetTemplate, Reward, RewardType};
+use crate::seasons::{self, LeaderboardMetric};
+use crate::stardust_token::StardustFlow;
+use crate::{asset_classes, asset_storage, level_calculation, level_rewards, player_storage, stardust_token};
+
+/// Progression Module for StardustEngine
+/// Player experience and levels, and the rewards granted by missions, levels and seasons
+#[multiversx_sc::module]
+pub trait Progression:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + level_calculation::LevelCalculation
+    + level_rewards::LevelRewards
+    + seasons::Seasons
+    + stardust_token::StardustToken
+{
+    
+    // ===== EXPERIENCE =====
+    
+    /// Adds player experience and counts it towards the current season.
+    /// Levels never drop, even when a new curve puts the experience below them.
+    fn add_player_experience(&self, player: &ManagedAddress, amount: u64) {
+        let (old_level, new_level) = self.player_stats(player).update(|stats| {
+            let old_level = stats.level;
+            stats.experience += amount;
+            stats.level = core::cmp::max(stats.level, self.calculate_level(stats.experience));
+            (old_level, stats.level)
+        });
+        self.add_season_score(LeaderboardMetric::Experience, player, amount);
+        if new_level > old_level {
+            self.player_level_up_event(player, old_level, new_level);
+            self.grant_level_rewards(player, old_level, new_level);
+        }
+    }
+    
+    /// Grants the rewards of every level crossed, each level at most once per player.
+    /// Experience rewards may cross further levels, those are granted by the nested call.
+    fn grant_level_rewards(&self, player: &ManagedAddress, old_level: u32, new_level: u32) {
+        let granted_mapper = self.level_rewards_granted(player);
+        let granted_level = if granted_mapper.is_empty() { old_level } else { granted_mapper.get() };
+        if granted_level >= new_level {
+            return;
+        }
+        granted_mapper.set(new_level);
+        
+        for level in granted_level + 1..=new_level {
+            let rewards = self.level_rewards(level).get();
+            if rewards.is_empty() {
+                continue;
+            }
+            for reward in rewards.iter() {
+                self.grant_reward(player, &reward);
+            }
+            self.level_reward_granted_event(player, level, rewards.len() as u32);
+        }
+    }
+    
+    // ===== SEASON REWARDS =====
+    
+    /// Grants the configured rewards to the top placements of every leaderboard once the season ended.
+    #[endpoint]
+    fn distribute_season_rewards(&self, season_id: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        let mut season = self.require_season(season_id);
+        require!(self.blockchain().get_block_timestamp() >= season.end_time, "Season not ended");
+        require!(!season.rewards_distributed, "Season rewards already distributed");
+        
+        for metric in LeaderboardMetric::ALL.iter() {
+            let rewards = self.season_rewards(season_id, metric).get();
+            let leaderboard = self.leaderboard(season_id, metric);
+            for rank in 1..=core::cmp::min(rewards.len(), leaderboard.len()) {
+                let entry = leaderboard.get(rank);
+                let reward = rewards.get(rank - 1);
+                self.grant_reward(&entry.player, &reward);
+                self.season_reward_granted_event(season_id, metric, &entry.player, rank as u32);
+            }
+        }
+        
+        season.rewards_distributed = true;
+        self.seasons().set(season_id as usize, &season);
+        self.season_rewards_distributed_event(season_id);
+    }
+    
+    // ===== REWARDS =====
+    
+    fn grant_reward(&self, player: &ManagedAddress, reward: &Reward<Self::Api>) {
+        match reward.reward_type {
+            RewardType::Experience => {
+                self.add_player_experience(player, reward.amount);
+            },
+            RewardType::StardustPoints => {
+                self.mint_stardust(player, reward.amount, StardustFlow::Reward);
+            },
+            RewardType::Asset => {
+                self.mint_reward_asset(player, reward.asset_template.as_ref().unwrap());
+            },
+            RewardType::Title => {
+                self.player_titles(player).insert(reward.title.as_ref().unwrap().clone());
+            }
+        }
+    }
+    
+    fn mint_reward_asset(&self, player: &ManagedAddress, template: &AssetTemplate<Self::Api>) {
+        let asset_id = self.create_game_asset(
+            player,
+            template.asset_type.clone(),
+            template.rarity.clone(),
+            template.name.clone(),
+            template.description.clone(),
+        );
+        
+        self.asset_minted_event(player, asset_id, &template.asset_type, &template.rarity, &template.name, &EgldOrEsdtTokenIdentifier::egld(), &BigUint::zero());
+    }
+}
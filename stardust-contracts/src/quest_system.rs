@@ -1,14 +1,35 @@
 use multiversx_sc::imports::*;
-use crate::stardust_contracts::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::asset_storage::{self, AssetType, GameAsset, Rarity};
+use crate::access_control::{self, Role};
+use crate::pause::{self, Subsystem};
+use crate::{asset_classes, level_calculation, level_rewards, player_storage, progression, seasons, stardust_token};
 
 /// Quest System Module for StardustEngine
 /// Implements story-driven missions and objectives
-pub trait QuestSystem {
+#[multiversx_sc::module]
+pub trait QuestSystem:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + level_calculation::LevelCalculation
+    + level_rewards::LevelRewards
+    + pause::Pause
+    + seasons::Seasons
+    + progression::Progression
+    + stardust_token::StardustToken
+{
     
     // ===== QUEST MANAGEMENT =====
     
+    /// Starts a mission. Assets the mission requires are sent along with the call
+    /// and returned right away, holding the NFT is the proof of ownership.
     #[endpoint]
+    #[payable("*")]
     fn start_mission(&self, mission_id: u64) {
+        self.require_not_paused(Subsystem::Quests);
         let caller = self.blockchain().get_caller();
         require!(self.players().contains(&caller), "Player not registered");
         require!(!self.active_missions(&caller).contains(&mission_id), "Mission already active");
@@ -19,7 +40,8 @@ pub trait QuestSystem {
         let mission_template = mission.unwrap();
         
         // Check requirements
-        self.validate_mission_requirements(&caller, &mission_template);
+        let proof_assets = self.receive_proof_assets(&caller);
+        self.validate_mission_requirements(&caller, &mission_template, &proof_assets);
         
         let player_mission = PlayerMission {
             mission_id,
@@ -36,13 +58,11 @@ pub trait QuestSystem {
         self.mission_started_event(&caller, mission_id);
     }
     
+    /// Completes an objective of an active mission. Asset proofs are the game asset NFTs
+    /// sent along with the call, they are returned to the player right away.
     #[endpoint]
-    fn complete_objective(
-        &self,
-        mission_id: u64,
-        objective_id: u64,
-        proof_assets: ManagedVec<u64>,
-    ) {
+    #[payable("*")]
+    fn complete_objective(&self, mission_id: u64, objective_id: u64) {
         let caller = self.blockchain().get_caller();
         require!(self.active_missions(&caller).contains(&mission_id), "Mission not active");
         
@@ -51,6 +71,7 @@ pub trait QuestSystem {
         require!(!player_mission.objectives_completed.contains(&objective_id), "Objective already completed");
         
         // Validate objective completion
+        let proof_assets = self.receive_proof_assets(&caller);
         self.validate_objective_completion(&caller, mission_id, objective_id, &proof_assets);
         
         player_mission.objectives_completed.push(objective_id);
@@ -72,42 +93,29 @@ pub trait QuestSystem {
         
         // Award rewards
         for reward in mission_template.rewards.iter() {
-            match reward.reward_type {
-                RewardType::Experience => {
-                    self.player_stats(player).update(|stats| {
-                        stats.experience += reward.amount;
-                        stats.level = self.calculate_level(stats.experience);
-                    });
-                },
-                RewardType::StardustPoints => {
-                    self.player_stardust_points(player).update(|points| *points += reward.amount);
-                },
-                RewardType::Asset => {
-                    // Mint reward asset
-                    self.mint_reward_asset(player, reward.asset_template.as_ref().unwrap());
-                },
-                RewardType::Title => {
-                    self.player_titles(player).insert(reward.title.as_ref().unwrap().clone());
-                }
-            }
+            self.grant_reward(player, &reward);
         }
         
         // Update mission completion stats
         self.player_stats(player).update(|stats| {
-            if let Some(ref mut achievements) = Some(&mut stats.achievements) {
-                if mission_template.chapter == 1 && !achievements.contains(&ManagedBuffer::from(b"Chapter 1 Complete")) {
-                    achievements.push(ManagedBuffer::from(b"Chapter 1 Complete"));
-                }
+            let chapter_achievement = ManagedBuffer::from(b"Chapter 1 Complete");
+            if mission_template.chapter == 1 && !stats.achievements.contains(&chapter_achievement) {
+                stats.achievements.push(chapter_achievement);
             }
         });
         
-        self.active_missions(player).remove(&mission_id);
+        self.active_missions(player).swap_remove(&mission_id);
         self.completed_missions(player).insert(mission_id);
         
         self.mission_completed_event(player, mission_id);
     }
     
-    fn validate_mission_requirements(&self, player: &ManagedAddress, mission: &MissionTemplate<Self::Api>) {
+    fn validate_mission_requirements(
+        &self,
+        player: &ManagedAddress,
+        mission: &MissionTemplate<Self::Api>,
+        proof_assets: &ManagedVec<GameAsset<Self::Api>>,
+    ) {
         // Check level requirement
         let player_stats = self.player_stats(player).get();
         require!(player_stats.level >= mission.required_level, "Level requirement not met");
@@ -119,8 +127,7 @@ pub trait QuestSystem {
         
         // Check required assets
         for required_asset in mission.required_assets.iter() {
-            let player_assets = self.get_player_assets(player.clone());
-            let has_asset = player_assets.iter().any(|asset| 
+            let has_asset = proof_assets.iter().any(|asset| 
                 asset.asset_type == required_asset.asset_type && 
                 asset.rarity >= required_asset.min_rarity
             );
@@ -133,7 +140,7 @@ pub trait QuestSystem {
         player: &ManagedAddress,
         mission_id: u64,
         objective_id: u64,
-        proof_assets: &ManagedVec<u64>,
+        proof_assets: &ManagedVec<GameAsset<Self::Api>>,
     ) {
         let mission_template = self.get_mission_template(mission_id).unwrap();
         let objective = mission_template.objectives.get(objective_id as usize - 1);
@@ -141,9 +148,6 @@ pub trait QuestSystem {
         match objective.objective_type {
             ObjectiveType::CollectAssets => {
                 require!(proof_assets.len() >= objective.target_amount as usize, "Insufficient assets collected");
-                for asset_id in proof_assets.iter() {
-                    require!(self.asset_owner(asset_id).get() == *player, "Asset not owned");
-                }
             },
             ObjectiveType::WinBattles => {
                 let player_stats = self.player_stats(player).get();
@@ -164,37 +168,28 @@ pub trait QuestSystem {
         }
     }
     
-    fn mint_reward_asset(&self, player: &ManagedAddress, template: &AssetTemplate<Self::Api>) {
-        let asset_id = self.next_asset_id().get();
-        self.next_asset_id().set(asset_id + 1);
-        
-        let asset = GameAsset {
-            id: asset_id,
-            owner: player.clone(),
-            asset_type: template.asset_type.clone(),
-            rarity: template.rarity.clone(),
-            name: template.name.clone(),
-            description: template.description.clone(),
-            created_at: self.blockchain().get_block_timestamp(),
-            level: 1u32,
-            experience: 0u64,
-        };
+    /// Game assets sent along with the call, returned to the player once read.
+    /// Sending the NFT proves the player holds it, whichever shard the player is on.
+    fn receive_proof_assets(&self, player: &ManagedAddress) -> ManagedVec<GameAsset<Self::Api>> {
+        let asset_token_id = self.game_asset_token().get_token_id();
+        let mut assets = ManagedVec::new();
         
-        self.game_assets().insert(asset_id);
-        self.asset_owner(asset_id).set(player.clone());
-        self.asset_metadata(asset_id).set(asset);
+        for transfer in self.call_value().all_transfers().iter() {
+            require!(transfer.token_identifier == asset_token_id, "Only game assets accepted as proof");
+            require!(self.game_assets().contains(&transfer.token_nonce), "Asset does not exist");
+            self.set_asset_owner(transfer.token_nonce, player);
+            assets.push(self.asset_metadata(transfer.token_nonce).get());
+            self.send().direct_esdt(player, &asset_token_id, transfer.token_nonce, &transfer.amount);
+        }
         
-        // Update player stats
-        self.player_stats(player).update(|stats| {
-            stats.assets_owned += 1;
-        });
+        assets
     }
     
     // ===== STORY CHAPTERS =====
     
     #[endpoint]
     fn initialize_chapter_missions(&self) {
-        self.require_caller_is_admin();
+        self.require_caller_has_role(Role::QuestDesigner);
         
         // Chapter 1: The Awakening
         self.create_mission_template(1, MissionTemplate {
@@ -206,7 +201,7 @@ pub trait QuestSystem {
             total_objectives: 3,
             prerequisites: ManagedVec::new(),
             required_assets: ManagedVec::new(),
-            objectives: vec![
+            objectives: ManagedVec::from_iter([
                 Objective {
                     id: 1,
                     description: ManagedBuffer::from(b"Mint your first Common asset"),
@@ -225,8 +220,8 @@ pub trait QuestSystem {
                     objective_type: ObjectiveType::ReachLevel,
                     target_amount: 2,
                 }
-            ].into(),
-            rewards: vec![
+            ]),
+            rewards: ManagedVec::from_iter([
                 Reward {
                     reward_type: RewardType::Experience,
                     amount: 200,
@@ -250,7 +245,7 @@ pub trait QuestSystem {
                     asset_template: None,
                     title: Some(ManagedBuffer::from(b"Rookie Engineer")),
                 }
-            ].into(),
+            ]),
         });
         
         // Additional chapters would be added here...
@@ -337,12 +332,6 @@ pub trait QuestSystem {
     #[storage_mapper("playerMissionData")]
     fn player_mission_data(&self, player: &ManagedAddress, mission_id: u64) -> SingleValueMapper<PlayerMission<Self::Api>>;
     
-    #[storage_mapper("playerStardustPoints")]
-    fn player_stardust_points(&self, player: &ManagedAddress) -> SingleValueMapper<u64>;
-    
-    #[storage_mapper("playerTitles")]
-    fn player_titles(&self, player: &ManagedAddress) -> UnorderedSetMapper<ManagedBuffer>;
-    
     // ===== EVENTS =====
     
     #[event("missionStarted")]
@@ -370,7 +359,8 @@ pub trait QuestSystem {
 
 // ===== DATA STRUCTURES =====
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
 pub struct MissionTemplate<M: ManagedTypeApi> {
     pub id: u64,
     pub name: ManagedBuffer<M>,
@@ -384,7 +374,8 @@ pub struct MissionTemplate<M: ManagedTypeApi> {
     pub rewards: ManagedVec<M, Reward<M>>,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct PlayerMission<M: ManagedTypeApi> {
     pub mission_id: u64,
     pub player: ManagedAddress<M>,
@@ -394,7 +385,8 @@ pub struct PlayerMission<M: ManagedTypeApi> {
     pub objectives_completed: ManagedVec<M, u64>,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct Objective<M: ManagedTypeApi> {
     pub id: u64,
     pub description: ManagedBuffer<M>,
@@ -402,7 +394,8 @@ pub struct Objective<M: ManagedTypeApi> {
     pub target_amount: u32,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct Reward<M: ManagedTypeApi> {
     pub reward_type: RewardType,
     pub amount: u64,
@@ -410,7 +403,8 @@ pub struct Reward<M: ManagedTypeApi> {
     pub title: Option<ManagedBuffer<M>>,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct AssetTemplate<M: ManagedTypeApi> {
     pub asset_type: AssetType,
     pub rarity: Rarity,
@@ -418,13 +412,15 @@ pub struct AssetTemplate<M: ManagedTypeApi> {
     pub description: ManagedBuffer<M>,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
 pub struct RequiredAsset {
     pub asset_type: AssetType,
     pub min_rarity: Rarity,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
 pub enum MissionStatus {
     Available,
     Active,
@@ -432,7 +428,8 @@ pub enum MissionStatus {
     Failed,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
 pub enum ObjectiveType {
     CollectAssets,
     WinBattles,
@@ -441,7 +438,8 @@ pub enum ObjectiveType {
     ExploreTerritory,
 }
 
-#[derive(TypeAbi, TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone, PartialEq, Eq)]
 pub enum RewardType {
     Experience,
     StardustPoints,
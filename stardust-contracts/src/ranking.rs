@@ -0,0 +1,146 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+
+/// Rating every player starts ranked play with
+pub const DEFAULT_RATING: u32 = 1_200;
+
+/// Maximum rating points a single ranked battle can move
+pub const RATING_K_FACTOR: u32 = 32;
+
+/// Rating difference the ranked queue pairs within, used until a game master configures one
+pub const DEFAULT_RATING_WINDOW: u32 = 200;
+
+/// Expected score of the higher rated player in basis points, for rating differences
+/// of 0, 25, .., 400. Larger differences are treated as 400.
+pub const EXPECTED_SCORE_BPS: [u32; 17] = [
+    5_000, 5_359, 5_715, 6_063, 6_401, 6_725, 7_034, 7_325, 7_597,
+    7_850, 8_083, 8_296, 8_490, 8_666, 8_823, 8_965, 9_091,
+];
+
+const EXPECTED_SCORE_STEP: u32 = 25;
+
+/// Ranking Module for StardustEngine
+/// Elo ratings updated by ranked battles, with a per-player rating history
+#[multiversx_sc::module]
+pub trait Ranking: access_control::AccessControl {
+    
+    #[endpoint]
+    fn set_ranked_rating_window(&self, rating_window: u32) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(rating_window > 0, "Rating window must be positive");
+        
+        self.ranked_rating_window().set(rating_window);
+        self.ranked_rating_window_set_event(rating_window);
+    }
+    
+    /// Moves rating from the loser to the winner, more the less likely the win was.
+    fn update_ratings(&self, battle_id: u64, winner: &ManagedAddress, loser: &ManagedAddress) {
+        let winner_rating = self.get_player_rating(winner.clone());
+        let loser_rating = self.get_player_rating(loser.clone());
+        
+        let winner_expected_bps = if winner_rating >= loser_rating {
+            self.expected_score_bps(winner_rating - loser_rating)
+        } else {
+            10_000 - self.expected_score_bps(loser_rating - winner_rating)
+        };
+        let change = core::cmp::max(RATING_K_FACTOR * (10_000 - winner_expected_bps) / 10_000, 1);
+        
+        self.record_rating(battle_id, winner, loser, winner_rating, winner_rating + change);
+        self.record_rating(battle_id, loser, winner, loser_rating, loser_rating.saturating_sub(change));
+    }
+    
+    fn record_rating(
+        &self,
+        battle_id: u64,
+        player: &ManagedAddress,
+        opponent: &ManagedAddress,
+        old_rating: u32,
+        new_rating: u32,
+    ) {
+        self.player_rating(player).set(new_rating);
+        self.rating_history(player).push(&RatingChange {
+            battle_id,
+            opponent: opponent.clone(),
+            old_rating,
+            new_rating,
+            timestamp: self.blockchain().get_block_timestamp(),
+        });
+        self.rating_updated_event(player, battle_id, new_rating);
+    }
+    
+    /// Linear interpolation between the `EXPECTED_SCORE_BPS` steps.
+    fn expected_score_bps(&self, rating_difference: u32) -> u32 {
+        let last_index = EXPECTED_SCORE_BPS.len() - 1;
+        let index = (rating_difference / EXPECTED_SCORE_STEP) as usize;
+        if index >= last_index {
+            return EXPECTED_SCORE_BPS[last_index];
+        }
+        
+        let lower = EXPECTED_SCORE_BPS[index];
+        let upper = EXPECTED_SCORE_BPS[index + 1];
+        lower + (upper - lower) * (rating_difference % EXPECTED_SCORE_STEP) / EXPECTED_SCORE_STEP
+    }
+    
+    fn get_ranked_rating_window(&self) -> u32 {
+        if self.ranked_rating_window().is_empty() {
+            DEFAULT_RATING_WINDOW
+        } else {
+            self.ranked_rating_window().get()
+        }
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_player_rating(&self, player: ManagedAddress) -> u32 {
+        if self.player_rating(&player).is_empty() {
+            DEFAULT_RATING
+        } else {
+            self.player_rating(&player).get()
+        }
+    }
+    
+    /// Rating changes of a player, oldest first.
+    #[view]
+    fn get_rating_history(&self, player: ManagedAddress) -> MultiValueEncoded<RatingChange<Self::Api>> {
+        self.rating_history(&player).iter().collect()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("playerRating")]
+    fn player_rating(&self, player: &ManagedAddress) -> SingleValueMapper<u32>;
+    
+    #[storage_mapper("ratingHistory")]
+    fn rating_history(&self, player: &ManagedAddress) -> VecMapper<RatingChange<Self::Api>>;
+    
+    #[storage_mapper("rankedRatingWindow")]
+    fn ranked_rating_window(&self) -> SingleValueMapper<u32>;
+    
+    // ===== EVENTS =====
+    
+    #[event("rankedRatingWindowSet")]
+    fn ranked_rating_window_set_event(&self, rating_window: u32);
+    
+    #[event("ratingUpdated")]
+    fn rating_updated_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] battle_id: u64,
+        new_rating: u32,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RatingChange<M: ManagedTypeApi> {
+    pub battle_id: u64,
+    pub opponent: ManagedAddress<M>,
+    pub old_rating: u32,
+    pub new_rating: u32,
+    pub timestamp: u64,
+}
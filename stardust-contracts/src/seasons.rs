@@ -0,0 +1,278 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+use crate::quest_system::Reward;
+use crate::{level_rewards, player_storage};
+
+/// Placements kept per season leaderboard, players below the cutoff are not ranked
+pub const MAX_LEADERBOARD_SIZE: usize = 100;
+
+/// Seasons Module for StardustEngine
+/// Time-boxed seasons with incrementally maintained leaderboards and end-of-season rewards
+#[multiversx_sc::module]
+pub trait Seasons:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + level_rewards::LevelRewards
+{
+    
+    // ===== SEASON MANAGEMENT =====
+    
+    /// Schedules a season. Seasons run back to back and may not overlap.
+    #[endpoint]
+    fn create_season(&self, name: ManagedBuffer, start_time: u64, end_time: u64) -> u64 {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(start_time < end_time, "Invalid season period");
+        if !self.seasons().is_empty() {
+            let last_season = self.seasons().get(self.seasons().len());
+            require!(start_time >= last_season.end_time, "Seasons cannot overlap");
+        }
+        
+        let season_id = self.seasons().len() as u64 + 1;
+        self.seasons().push(&Season {
+            id: season_id,
+            name,
+            start_time,
+            end_time,
+            rewards_distributed: false,
+        });
+        
+        self.season_created_event(season_id, start_time, end_time);
+        season_id
+    }
+    
+    /// Sets the rewards of a leaderboard, the n-th reward goes to the n-th placement.
+    #[endpoint]
+    fn set_season_rewards(&self, season_id: u64, metric: LeaderboardMetric, rewards: MultiValueEncoded<Reward<Self::Api>>) {
+        self.require_caller_has_role(Role::GameMaster);
+        let season = self.require_season(season_id);
+        require!(!season.rewards_distributed, "Season rewards already distributed");
+        require!(rewards.len() <= MAX_LEADERBOARD_SIZE, "Too many reward placements");
+        
+        let rewards = rewards.to_vec();
+        for reward in rewards.iter() {
+            self.require_valid_reward(&reward);
+        }
+        
+        self.season_rewards(season_id, &metric).set(&rewards);
+        self.season_rewards_set_event(season_id, &metric, rewards.len() as u32);
+    }
+    
+    fn require_season(&self, season_id: u64) -> Season<Self::Api> {
+        require!(season_id >= 1 && season_id as usize <= self.seasons().len(), "Season not found");
+        self.seasons().get(season_id as usize)
+    }
+    
+    /// The season whose period contains the current block timestamp, if any.
+    fn current_season_id(&self) -> Option<u64> {
+        let now = self.blockchain().get_block_timestamp();
+        let mut season_id = self.seasons().len();
+        while season_id > 0 {
+            let season = self.seasons().get(season_id);
+            if season.start_time <= now {
+                return if now < season.end_time { Some(season_id as u64) } else { None };
+            }
+            season_id -= 1;
+        }
+        None
+    }
+    
+    // ===== PROGRESS TRACKING =====
+    
+    fn add_season_score(&self, metric: LeaderboardMetric, player: &ManagedAddress, amount: u64) {
+        let season_id = match self.current_season_id() {
+            Some(season_id) => season_id,
+            None => return,
+        };
+        let score = self.season_score(season_id, &metric, player).update(|score| {
+            *score += amount;
+            *score
+        });
+        self.update_leaderboard(season_id, &metric, player, score);
+    }
+    
+    fn set_season_score(&self, metric: LeaderboardMetric, player: &ManagedAddress, score: u64) {
+        let season_id = match self.current_season_id() {
+            Some(season_id) => season_id,
+            None => return,
+        };
+        self.season_score(season_id, &metric, player).set(score);
+        self.update_leaderboard(season_id, &metric, player, score);
+    }
+    
+    /// Moves the player to their new position in the leaderboard, highest score first.
+    /// Ties keep the earlier entry ahead, only the top `MAX_LEADERBOARD_SIZE` entries are kept.
+    /// Only the entries between the old and the new placement are rewritten.
+    fn update_leaderboard(&self, season_id: u64, metric: &LeaderboardMetric, player: &ManagedAddress, score: u64) {
+        let mut leaderboard = self.leaderboard(season_id, metric);
+        let entry = LeaderboardEntry {
+            player: player.clone(),
+            score,
+        };
+        let rank_mapper = self.leaderboard_rank(season_id, metric, player);
+        let mut rank = if !rank_mapper.is_empty() {
+            rank_mapper.get()
+        } else if leaderboard.len() < MAX_LEADERBOARD_SIZE {
+            leaderboard.push(&entry)
+        } else {
+            let last_rank = leaderboard.len();
+            let last = leaderboard.get(last_rank);
+            if last.score >= score {
+                return;
+            }
+            self.leaderboard_rank(season_id, metric, &last.player).clear();
+            last_rank
+        };
+        
+        while rank > 1 && leaderboard.get(rank - 1).score < score {
+            self.move_leaderboard_entry(&mut leaderboard, season_id, metric, rank - 1, rank);
+            rank -= 1;
+        }
+        while rank < leaderboard.len() && leaderboard.get(rank + 1).score >= score {
+            self.move_leaderboard_entry(&mut leaderboard, season_id, metric, rank + 1, rank);
+            rank += 1;
+        }
+        
+        leaderboard.set(rank, &entry);
+        rank_mapper.set(rank);
+    }
+    
+    fn move_leaderboard_entry(
+        &self,
+        leaderboard: &mut VecMapper<LeaderboardEntry<Self::Api>>,
+        season_id: u64,
+        metric: &LeaderboardMetric,
+        from_rank: usize,
+        to_rank: usize,
+    ) {
+        let entry = leaderboard.get(from_rank);
+        leaderboard.set(to_rank, &entry);
+        self.leaderboard_rank(season_id, metric, &entry.player).set(to_rank);
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_season(&self, season_id: u64) -> OptionalValue<Season<Self::Api>> {
+        if season_id == 0 || season_id as usize > self.seasons().len() {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.seasons().get(season_id as usize))
+        }
+    }
+    
+    #[view]
+    fn get_current_season(&self) -> OptionalValue<Season<Self::Api>> {
+        match self.current_season_id() {
+            Some(season_id) => OptionalValue::Some(self.seasons().get(season_id as usize)),
+            None => OptionalValue::None,
+        }
+    }
+    
+    /// Leaderboard page starting at placement `from` (0 is the leader), at most `count` entries.
+    #[view]
+    fn get_leaderboard(
+        &self,
+        season_id: u64,
+        metric: LeaderboardMetric,
+        from: usize,
+        count: usize,
+    ) -> MultiValueEncoded<LeaderboardEntry<Self::Api>> {
+        let leaderboard = self.leaderboard(season_id, &metric);
+        (from.saturating_add(1)..=leaderboard.len()).take(count).map(|rank| leaderboard.get(rank)).collect()
+    }
+    
+    #[view]
+    fn get_season_score(&self, season_id: u64, metric: LeaderboardMetric, player: ManagedAddress) -> u64 {
+        self.season_score(season_id, &metric, &player).get()
+    }
+    
+    #[view]
+    fn get_season_rewards(&self, season_id: u64, metric: LeaderboardMetric) -> ManagedVec<Reward<Self::Api>> {
+        self.season_rewards(season_id, &metric).get()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("seasons")]
+    fn seasons(&self) -> VecMapper<Season<Self::Api>>;
+    
+    #[storage_mapper("seasonScore")]
+    fn season_score(&self, season_id: u64, metric: &LeaderboardMetric, player: &ManagedAddress) -> SingleValueMapper<u64>;
+    
+    /// Leaderboard entries by placement, 1 is the leader.
+    #[storage_mapper("leaderboard")]
+    fn leaderboard(&self, season_id: u64, metric: &LeaderboardMetric) -> VecMapper<LeaderboardEntry<Self::Api>>;
+    
+    #[storage_mapper("leaderboardRank")]
+    fn leaderboard_rank(&self, season_id: u64, metric: &LeaderboardMetric, player: &ManagedAddress) -> SingleValueMapper<usize>;
+    
+    #[storage_mapper("seasonRewards")]
+    fn season_rewards(&self, season_id: u64, metric: &LeaderboardMetric) -> SingleValueMapper<ManagedVec<Reward<Self::Api>>>;
+    
+    // ===== EVENTS =====
+    
+    #[event("seasonCreated")]
+    fn season_created_event(
+        &self,
+        #[indexed] season_id: u64,
+        #[indexed] start_time: u64,
+        end_time: u64,
+    );
+    
+    #[event("seasonRewardsSet")]
+    fn season_rewards_set_event(
+        &self,
+        #[indexed] season_id: u64,
+        #[indexed] metric: &LeaderboardMetric,
+        placements: u32,
+    );
+    
+    #[event("seasonRewardGranted")]
+    fn season_reward_granted_event(
+        &self,
+        #[indexed] season_id: u64,
+        #[indexed] metric: &LeaderboardMetric,
+        #[indexed] player: &ManagedAddress,
+        placement: u32,
+    );
+    
+    #[event("seasonRewardsDistributed")]
+    fn season_rewards_distributed_event(&self, #[indexed] season_id: u64);
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Season<M: ManagedTypeApi> {
+    pub id: u64,
+    pub name: ManagedBuffer<M>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub rewards_distributed: bool,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, ManagedVecItem, Clone)]
+pub struct LeaderboardEntry<M: ManagedTypeApi> {
+    pub player: ManagedAddress<M>,
+    pub score: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum LeaderboardMetric {
+    Rating,
+    Wins,
+    Experience,
+}
+
+impl LeaderboardMetric {
+    pub const ALL: [LeaderboardMetric; 3] = [
+        LeaderboardMetric::Rating,
+        LeaderboardMetric::Wins,
+        LeaderboardMetric::Experience,
+    ];
+}
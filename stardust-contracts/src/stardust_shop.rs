@@ -0,0 +1,152 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+use crate::asset_storage::{self, AssetType, Rarity};
+use crate::pause::{self, Subsystem};
+use crate::quest_system::AssetTemplate;
+use crate::stardust_token::{self, StardustFlow, StardustSink};
+use crate::{asset_classes, player_storage};
+
+/// Stardust Shop Module for StardustEngine
+/// Common asset mints and a curated consumable shop paid in Stardust
+#[multiversx_sc::module]
+pub trait StardustShop:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + pause::Pause
+    + stardust_token::StardustToken
+{
+    
+    /// Mints a Common asset of any type for the Stardust asset mint price.
+    #[endpoint]
+    #[payable("*")]
+    fn mint_asset_with_stardust(&self, asset_type: AssetType, name: ManagedBuffer, description: ManagedBuffer) -> u64 {
+        self.require_not_paused(Subsystem::Minting);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        
+        let price = self.get_stardust_sink_price(StardustSink::AssetMint);
+        self.spend_stardust(&caller, price, self.optional_payment(), StardustFlow::AssetMint);
+        
+        let asset_id = self.create_game_asset(&caller, asset_type.clone(), Rarity::Common, name.clone(), description);
+        self.asset_minted_with_stardust_event(&caller, asset_id, &asset_type, &Rarity::Common, &name, price);
+        asset_id
+    }
+    
+    /// Lists a consumable in the shop with a limited stock.
+    #[endpoint]
+    fn add_shop_item(&self, template: AssetTemplate<Self::Api>, price: u64, stock: u32) -> u64 {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(template.asset_type.is_consumable(), "Shop only sells consumables");
+        require!(price > 0, "Stardust price must be positive");
+        require!(stock > 0, "Stock must be positive");
+        
+        let item_id = self.next_shop_item_id().get() + 1;
+        self.next_shop_item_id().set(item_id);
+        self.shop_items().insert(item_id);
+        self.shop_item(item_id).set(ShopItem {
+            id: item_id,
+            template,
+            price,
+            stock,
+        });
+        
+        self.shop_item_added_event(item_id, price, stock);
+        item_id
+    }
+    
+    #[endpoint]
+    fn remove_shop_item(&self, item_id: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(self.shop_items().swap_remove(&item_id), "Shop item not found");
+        
+        self.shop_item(item_id).clear();
+        self.shop_item_removed_event(item_id);
+    }
+    
+    #[endpoint]
+    #[payable("*")]
+    fn buy_shop_item(&self, item_id: u64) -> u64 {
+        self.require_not_paused(Subsystem::Minting);
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        require!(self.shop_items().contains(&item_id), "Shop item not found");
+        
+        let mut item = self.shop_item(item_id).get();
+        require!(item.stock > 0, "Shop item sold out");
+        self.spend_stardust(&caller, item.price, self.optional_payment(), StardustFlow::ShopPurchase);
+        
+        item.stock -= 1;
+        self.shop_item(item_id).set(&item);
+        
+        let template = item.template;
+        let asset_id = self.create_game_asset(
+            &caller,
+            template.asset_type,
+            template.rarity,
+            template.name,
+            template.description,
+        );
+        self.shop_item_bought_event(&caller, item_id, asset_id);
+        asset_id
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_shop_items(&self) -> MultiValueEncoded<ShopItem<Self::Api>> {
+        self.shop_items().iter().map(|item_id| self.shop_item(item_id).get()).collect()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("shopItems")]
+    fn shop_items(&self) -> UnorderedSetMapper<u64>;
+    
+    #[storage_mapper("shopItem")]
+    fn shop_item(&self, item_id: u64) -> SingleValueMapper<ShopItem<Self::Api>>;
+    
+    #[storage_mapper("nextShopItemId")]
+    fn next_shop_item_id(&self) -> SingleValueMapper<u64>;
+    
+    // ===== EVENTS =====
+    
+    #[event("assetMintedWithStardust")]
+    fn asset_minted_with_stardust_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] asset_id: u64,
+        #[indexed] asset_type: &AssetType,
+        #[indexed] rarity: &Rarity,
+        #[indexed] name: &ManagedBuffer,
+        price: u64,
+    );
+    
+    #[event("shopItemAdded")]
+    fn shop_item_added_event(&self, #[indexed] item_id: u64, #[indexed] price: u64, stock: u32);
+    
+    #[event("shopItemRemoved")]
+    fn shop_item_removed_event(&self, #[indexed] item_id: u64);
+    
+    #[event("shopItemBought")]
+    fn shop_item_bought_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] item_id: u64,
+        asset_id: u64,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct ShopItem<M: ManagedTypeApi> {
+    pub id: u64,
+    pub template: AssetTemplate<M>,
+    pub price: u64,
+    pub stock: u32,
+}
@@ -0,0 +1,183 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+use crate::player_storage;
+
+/// Stardust price of minting a Common asset until a game master configures one
+pub const DEFAULT_ASSET_MINT_STARDUST_PRICE: u64 = 200;
+
+/// Stardust price of re-rolling a tournament seed until a game master configures one
+pub const DEFAULT_TOURNAMENT_REROLL_STARDUST_PRICE: u64 = 50;
+
+/// Stardust Token Module for StardustEngine
+/// Fungible Stardust token minted for point rewards, replacing the storage point balances.
+/// Every credit and debit is logged with its flow for economy audits.
+#[multiversx_sc::module]
+pub trait StardustToken: access_control::AccessControl + player_storage::PlayerStorage {
+    
+    #[endpoint]
+    fn set_stardust_sink_price(&self, sink: StardustSink, price: u64) {
+        self.require_caller_has_role(Role::GameMaster);
+        require!(price > 0, "Stardust price must be positive");
+        
+        self.stardust_sink_price(&sink).set(price);
+        self.stardust_sink_price_set_event(&sink, price);
+    }
+    
+    /// Converts the caller's stored Stardust Points into Stardust tokens, once the token is issued.
+    #[endpoint]
+    fn migrate_stardust_points(&self) -> u64 {
+        let caller = self.blockchain().get_caller();
+        require!(self.players().contains(&caller), "Player not registered");
+        require!(self.is_stardust_token_issued(), "Stardust token not issued");
+        
+        let points = self.player_stardust_points(&caller).take();
+        require!(points > 0, "No Stardust Points to migrate");
+        
+        self.stardust_token().mint_and_send(&caller, BigUint::from(points));
+        self.stardust_points_migrated_event(&caller, points);
+        points
+    }
+    
+    /// Mints Stardust tokens to the player. Until the token is issued the points
+    /// are credited to the stored balance, to be migrated later.
+    fn mint_stardust(&self, player: &ManagedAddress, amount: u64, flow: StardustFlow) {
+        if amount == 0 {
+            return;
+        }
+        
+        if self.is_stardust_token_issued() {
+            self.stardust_token().mint_and_send(player, BigUint::from(amount));
+        } else {
+            self.player_stardust_points(player).update(|points| *points += amount);
+        }
+        self.stardust_credited_event(player, &flow, amount);
+    }
+    
+    /// Takes `amount` Stardust from the player, paid with Stardust tokens sent along with the call
+    /// or, until the token is issued, from the stored balance. Token payments are burned.
+    /// Stored balances have to be migrated before they can be spent as tokens.
+    fn spend_stardust(
+        &self,
+        player: &ManagedAddress,
+        amount: u64,
+        payment: Option<EgldOrEsdtTokenPayment>,
+        flow: StardustFlow,
+    ) {
+        match payment {
+            Some(payment) => {
+                require!(self.is_stardust_payment(&payment), "Invalid payment token");
+                require!(payment.amount == amount, "Wrong Stardust payment");
+                self.stardust_token().burn(&payment.amount);
+            },
+            None => {
+                require!(!self.is_stardust_token_issued(), "Stardust token payment required");
+                let points = self.player_stardust_points(player).get();
+                require!(points >= amount, "Not enough Stardust Points");
+                self.player_stardust_points(player).set(points - amount);
+            },
+        }
+        self.stardust_debited_event(player, &flow, amount);
+    }
+    
+    /// The single payment sent along with the call, if any.
+    fn optional_payment(&self) -> Option<EgldOrEsdtTokenPayment> {
+        let transfers = self.call_value().all_transfers();
+        require!(transfers.len() <= 1, "Only one token payment allowed");
+        if transfers.is_empty() {
+            None
+        } else {
+            Some(transfers.get(0).clone())
+        }
+    }
+    
+    fn is_stardust_payment(&self, payment: &EgldOrEsdtTokenPayment) -> bool {
+        self.is_stardust_token_issued()
+            && payment.token_identifier == EgldOrEsdtTokenIdentifier::esdt(self.stardust_token().get_token_id())
+    }
+    
+    /// The mapper holds a pending marker while the issue call is in flight,
+    /// the token only counts as issued once the callback stored its id.
+    fn is_stardust_token_issued(&self) -> bool {
+        self.stardust_token().get_token_state().is_set()
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_stardust_token_id(&self) -> OptionalValue<TokenIdentifier> {
+        if self.is_stardust_token_issued() {
+            OptionalValue::Some(self.stardust_token().get_token_id())
+        } else {
+            OptionalValue::None
+        }
+    }
+    
+    #[view]
+    fn get_stardust_sink_price(&self, sink: StardustSink) -> u64 {
+        if !self.stardust_sink_price(&sink).is_empty() {
+            return self.stardust_sink_price(&sink).get();
+        }
+        
+        match sink {
+            StardustSink::AssetMint => DEFAULT_ASSET_MINT_STARDUST_PRICE,
+            StardustSink::TournamentReroll => DEFAULT_TOURNAMENT_REROLL_STARDUST_PRICE,
+        }
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("stardustToken")]
+    fn stardust_token(&self) -> FungibleTokenMapper;
+    
+    #[storage_mapper("stardustSinkPrice")]
+    fn stardust_sink_price(&self, sink: &StardustSink) -> SingleValueMapper<u64>;
+    
+    // ===== EVENTS =====
+    
+    #[event("stardustPointsMigrated")]
+    fn stardust_points_migrated_event(&self, #[indexed] player: &ManagedAddress, amount: u64);
+    
+    #[event("stardustSinkPriceSet")]
+    fn stardust_sink_price_set_event(&self, #[indexed] sink: &StardustSink, price: u64);
+    
+    #[event("stardustCredited")]
+    fn stardust_credited_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] flow: &StardustFlow,
+        amount: u64,
+    );
+    
+    #[event("stardustDebited")]
+    fn stardust_debited_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] flow: &StardustFlow,
+        amount: u64,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+/// Why Stardust was credited to or debited from a player.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum StardustFlow {
+    RegistrationBonus,
+    Reward,
+    BattleLoot,
+    AssetMint,
+    AssetUpgrade,
+    TournamentReroll,
+    ShopPurchase,
+}
+
+/// Purchases priced with `set_stardust_sink_price`.
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum StardustSink {
+    AssetMint,
+    TournamentReroll,
+}
@@ -0,0 +1,831 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::battle_randomness;
+use crate::combat_system::{self, BattleStatus, BattleType};
+use crate::access_control::Role;
+use crate::pause::{self, Subsystem};
+use crate::stardust_token::{StardustFlow, StardustSink};
+use crate::treasury::{self, RevenueSource};
+use crate::{access_control, asset_classes, asset_storage, level_calculation, level_rewards, player_storage, progression, ranking, seasons, stardust_token};
+
+/// Basis points denominator used for prize shares
+pub const TOTAL_BASIS_POINTS: u64 = 10_000;
+
+/// Default prize shares per placement tier: champion, runner-up, semi-finalists
+pub const DEFAULT_PRIZE_SHARES: [u64; 3] = [5_000, 3_000, 2_000];
+
+/// Upper bound for each of the organizer and platform fees
+pub const MAX_TOURNAMENT_FEE_BPS: u64 = 2_000;
+
+/// Minimum participants used until an admin configures one
+pub const DEFAULT_MIN_PARTICIPANTS: u32 = 2;
+
+/// Tournament System Module for StardustEngine
+/// Implements single-elimination tournaments with escrowed prize pools
+#[multiversx_sc::module]
+pub trait TournamentSystem:
+    access_control::AccessControl
+    + player_storage::PlayerStorage
+    + asset_classes::AssetClasses
+    + asset_storage::AssetStorage
+    + level_calculation::LevelCalculation
+    + level_rewards::LevelRewards
+    + combat_system::CombatSystem
+    + pause::Pause
+    + battle_randomness::BattleRandomness
+    + treasury::Treasury
+    + ranking::Ranking
+    + seasons::Seasons
+    + progression::Progression
+    + stardust_token::StardustToken
+{
+    
+    // ===== TOURNAMENT MANAGEMENT =====
+    
+    #[endpoint]
+    #[payable("EGLD")]
+    fn create_tournament(
+        &self,
+        name: ManagedBuffer,
+        entry_fee: BigUint,
+        max_participants: u32,
+        start_time: u64,
+        organizer_fee_bps: u64,
+        prize_shares: MultiValueEncoded<u64>,
+    ) -> u64 {
+        let prize_pool = self.call_value().egld().clone_value();
+        require!(prize_pool > BigUint::zero(), "Prize pool required");
+        require!(max_participants >= 2, "At least 2 participants required");
+        require!(organizer_fee_bps <= MAX_TOURNAMENT_FEE_BPS, "Organizer fee too high");
+        
+        // The default shares may name more tiers than a small bracket has,
+        // `distribute_prize_pool` pays the shares of missing tiers to the champion
+        let prize_shares = if prize_shares.is_empty() {
+            ManagedVec::from_iter(DEFAULT_PRIZE_SHARES)
+        } else {
+            let prize_shares = prize_shares.to_vec();
+            self.validate_prize_shares(&prize_shares, max_participants);
+            prize_shares
+        };
+        
+        let tournament_id = self.next_tournament_id().get();
+        self.next_tournament_id().set(tournament_id + 1);
+        
+        let tournament = Tournament {
+            id: tournament_id,
+            name: name.clone(),
+            organizer: self.blockchain().get_caller(),
+            entry_fee,
+            prize_pool: prize_pool.clone(),
+            seed_prize_pool: prize_pool.clone(),
+            max_participants,
+            current_participants: 0u32,
+            start_time,
+            status: TournamentStatus::Registration,
+            participants: ManagedVec::new(),
+            current_round: 0u32,
+            winner: None,
+            prize_shares,
+            organizer_fee_bps,
+            platform_fee_bps: self.tournament_platform_fee_bps().get(),
+        };
+        
+        self.tournaments().insert(tournament_id);
+        self.tournament_data(tournament_id).set(tournament);
+        
+        self.add_escrow(&EgldOrEsdtTokenIdentifier::egld(), &prize_pool);
+        self.tournament_created_event(tournament_id, &name, &prize_pool);
+        tournament_id
+    }
+    
+    #[endpoint]
+    #[payable("EGLD")]
+    fn join_tournament(&self, tournament_id: u64) {
+        self.require_not_paused(Subsystem::Tournaments);
+        let payment = self.call_value().egld().clone_value();
+        let caller = self.blockchain().get_caller();
+        
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        require!(self.players().contains(&caller), "Player not registered");
+        
+        let mut tournament = self.tournament_data(tournament_id).get();
+        require!(tournament.status == TournamentStatus::Registration, "Registration closed");
+        require!(tournament.current_participants < tournament.max_participants, "Tournament full");
+        require!(payment >= tournament.entry_fee, "Insufficient entry fee");
+        require!(!tournament.participants.contains(&caller), "Already registered");
+        
+        // Only the entry fee enters the prize pool, any surplus goes back to the caller
+        let entry_fee = tournament.entry_fee.clone();
+        let surplus = payment - &entry_fee;
+        
+        tournament.participants.push(caller.clone());
+        tournament.current_participants += 1;
+        tournament.prize_pool += &entry_fee;
+        
+        self.tournament_data(tournament_id).set(tournament);
+        self.tournament_entry_paid(tournament_id, &caller).set(&entry_fee);
+        self.add_escrow(&EgldOrEsdtTokenIdentifier::egld(), &entry_fee);
+        
+        if surplus > 0u32 {
+            self.send().direct_egld(&caller, &surplus);
+        }
+        
+        self.tournament_joined_event(&caller, tournament_id, &entry_fee);
+    }
+    
+    /// Leaves a tournament that is still open for registration and refunds the entry fee.
+    #[endpoint]
+    fn leave_tournament(&self, tournament_id: u64) {
+        let caller = self.blockchain().get_caller();
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let mut tournament = self.tournament_data(tournament_id).get();
+        require!(tournament.status == TournamentStatus::Registration, "Registration closed");
+        
+        let participant_index = match tournament.participants.find(&caller) {
+            Some(index) => index,
+            None => sc_panic!("Not registered in tournament"),
+        };
+        
+        let refund = self.tournament_entry_paid(tournament_id, &caller).take();
+        self.tournament_rerolled_players(tournament_id).swap_remove(&caller);
+        tournament.participants.remove(participant_index);
+        tournament.current_participants -= 1;
+        tournament.prize_pool -= &refund;
+        
+        self.tournament_data(tournament_id).set(tournament);
+        self.release_escrow(&EgldOrEsdtTokenIdentifier::egld(), &refund);
+        
+        if refund > 0u32 {
+            self.send().direct_egld(&caller, &refund);
+        }
+        self.tournament_left_event(&caller, tournament_id, &refund);
+    }
+    
+    /// Swaps the caller's entry with the seed of a random player who also rerolled in this tournament,
+    /// possibly their own, paid in Stardust. Players who never rerolled keep their join order seed,
+    /// so a reroll cannot take the bye of a top seed that did not agree to the shuffle.
+    /// Seeds decide the first round pairing when the tournament starts.
+    #[endpoint]
+    #[payable("*")]
+    fn reroll_tournament_seed(&self, tournament_id: u64) -> u32 {
+        self.require_not_paused(Subsystem::Tournaments);
+        let caller = self.blockchain().get_caller();
+        require!(!self.blockchain().is_smart_contract(&caller), "Only user accounts");
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let mut tournament = self.tournament_data(tournament_id).get();
+        require!(tournament.status == TournamentStatus::Registration, "Registration closed");
+        let participant_index = match tournament.participants.find(&caller) {
+            Some(index) => index,
+            None => sc_panic!("Not registered in tournament"),
+        };
+        
+        let price = self.get_stardust_sink_price(StardustSink::TournamentReroll);
+        self.spend_stardust(&caller, price, self.optional_payment(), StardustFlow::TournamentReroll);
+        
+        let mut rerolled_players = self.tournament_rerolled_players(tournament_id);
+        rerolled_players.insert(caller.clone());
+        let mut rerolled_indexes = ManagedVec::<Self::Api, usize>::new();
+        for (index, participant) in tournament.participants.iter().enumerate() {
+            if rerolled_players.contains(&participant) {
+                rerolled_indexes.push(index);
+            }
+        }
+        let pick = RandomnessSource::new().next_usize_in_range(0, rerolled_indexes.len());
+        let new_index = rerolled_indexes.get(pick);
+        let swapped = tournament.participants.get(new_index).clone();
+        let _ = tournament.participants.set(participant_index, swapped);
+        let _ = tournament.participants.set(new_index, caller.clone());
+        self.tournament_data(tournament_id).set(&tournament);
+        
+        let new_seed = new_index as u32 + 1;
+        self.tournament_seed_rerolled_event(&caller, tournament_id, new_seed);
+        new_seed
+    }
+    
+    /// Cancels a tournament, refunding every entry fee and returning the seeded prize pool to the organizer.
+    /// The organizer or an admin can cancel any tournament that has not completed yet.
+    /// Anyone can cancel once `start_time` has passed with fewer than the minimum participants.
+    #[endpoint]
+    fn cancel_tournament(&self, tournament_id: u64) {
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let mut tournament = self.tournament_data(tournament_id).get();
+        require!(
+            tournament.status == TournamentStatus::Registration || tournament.status == TournamentStatus::Active,
+            "Tournament already finished"
+        );
+        if !self.is_cancellation_eligible(&tournament) {
+            self.require_organizer_or_operator(&tournament);
+        }
+        
+        for participant in tournament.participants.iter() {
+            let refund = self.tournament_entry_paid(tournament_id, &participant).take();
+            if refund > 0u32 {
+                self.send().direct_egld(&participant, &refund);
+                self.tournament_refunded_event(&participant, tournament_id, &refund);
+            }
+        }
+        
+        if tournament.seed_prize_pool > 0u32 {
+            self.send().direct_egld(&tournament.organizer, &tournament.seed_prize_pool);
+            self.tournament_refunded_event(&tournament.organizer, tournament_id, &tournament.seed_prize_pool);
+        }
+        
+        self.release_escrow(&EgldOrEsdtTokenIdentifier::egld(), &tournament.prize_pool);
+        tournament.status = TournamentStatus::Cancelled;
+        tournament.prize_pool = BigUint::zero();
+        self.tournament_data(tournament_id).set(&tournament);
+        
+        self.tournament_cancelled_event(tournament_id, tournament.current_participants);
+    }
+    
+    #[endpoint]
+    fn set_tournament_min_participants(&self, min_participants: u32) {
+        self.require_caller_has_role(Role::TournamentOperator);
+        require!(min_participants >= 2, "At least 2 participants required");
+        
+        self.tournament_min_participants().set(min_participants);
+        self.tournament_min_participants_set_event(min_participants);
+    }
+    
+    #[endpoint]
+    fn set_tournament_platform_fee(&self, platform_fee_bps: u64) {
+        self.require_caller_has_role(Role::TournamentOperator);
+        require!(platform_fee_bps <= MAX_TOURNAMENT_FEE_BPS, "Platform fee too high");
+        
+        self.tournament_platform_fee_bps().set(platform_fee_bps);
+        self.tournament_platform_fee_set_event(platform_fee_bps);
+    }
+    
+    /// Prize shares are basis points per placement tier and must add up to 100%.
+    /// A single share of 10000 is winner-takes-all.
+    fn validate_prize_shares(&self, prize_shares: &ManagedVec<u64>, max_participants: u32) {
+        require!(!prize_shares.is_empty(), "Prize shares required");
+        
+        // A bracket of N players has log2(N) rounds, plus the champion tier
+        let placement_tiers = max_participants.next_power_of_two().trailing_zeros() as usize + 1;
+        require!(prize_shares.len() <= placement_tiers, "Too many prize shares");
+        
+        let mut total_bps = 0u64;
+        for share_bps in prize_shares.iter() {
+            require!(share_bps > 0, "Prize share must be positive");
+            total_bps += share_bps;
+        }
+        require!(total_bps == TOTAL_BASIS_POINTS, "Prize shares must add up to 10000");
+    }
+    
+    fn get_min_participants(&self) -> u32 {
+        if self.tournament_min_participants().is_empty() {
+            DEFAULT_MIN_PARTICIPANTS
+        } else {
+            self.tournament_min_participants().get()
+        }
+    }
+    
+    fn is_cancellation_eligible(&self, tournament: &Tournament<Self::Api>) -> bool {
+        tournament.status == TournamentStatus::Registration
+            && self.blockchain().get_block_timestamp() >= tournament.start_time
+            && tournament.current_participants < self.get_min_participants()
+    }
+    
+    /// Closes registration and generates the first round of the bracket.
+    /// Only the organizer or an admin can start, and only once `start_time` has passed.
+    #[endpoint]
+    fn start_tournament(&self, tournament_id: u64) {
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let mut tournament = self.tournament_data(tournament_id).get();
+        self.require_organizer_or_operator(&tournament);
+        require!(tournament.status == TournamentStatus::Registration, "Tournament already started");
+        require!(self.blockchain().get_block_timestamp() >= tournament.start_time, "Start time not reached");
+        require!(tournament.current_participants >= self.get_min_participants(), "Not enough participants");
+        
+        // Seeds missing from a full bracket are byes, they fall to the top seeds
+        let participant_count = tournament.participants.len();
+        let bracket_size = participant_count.next_power_of_two();
+        let mut round_slots = ManagedVec::new();
+        for seed in self.bracket_seed_order(bracket_size).iter() {
+            let slot = if seed < participant_count {
+                Some(tournament.participants.get(seed).clone())
+            } else {
+                None
+            };
+            round_slots.push(slot);
+        }
+        
+        tournament.status = TournamentStatus::Active;
+        self.tournament_data(tournament_id).set(&tournament);
+        self.tournament_started_event(tournament_id, tournament.current_participants);
+        
+        self.create_bracket_round(tournament_id, 1u32, &round_slots);
+    }
+    
+    /// Seed indexes in bracket order for a power of two bracket size, e.g. 1-8, 4-5, 2-7, 3-6.
+    /// Each round doubles the bracket and pairs every seed with its mirror, so the top two seeds
+    /// sit in opposite halves and the better seeds can only meet in the later rounds.
+    fn bracket_seed_order(&self, bracket_size: usize) -> ManagedVec<usize> {
+        let mut order = ManagedVec::new();
+        order.push(0usize);
+        while order.len() < bracket_size {
+            let mirror = order.len() * 2 - 1;
+            let mut next_order = ManagedVec::new();
+            for seed in order.iter() {
+                next_order.push(seed);
+                next_order.push(mirror - seed);
+            }
+            order = next_order;
+        }
+        order
+    }
+    
+    /// Challenges the other player of an undecided bracket match in the current round
+    /// with the game asset NFTs sent along with the call. A match has one battle at a time,
+    /// a new one can only be started once the previous challenge was cancelled.
+    #[endpoint]
+    #[payable("*")]
+    fn initiate_tournament_battle(
+        &self,
+        tournament_id: u64,
+        match_id: usize,
+        commitment: OptionalValue<ManagedByteArray<32>>,
+    ) -> u64 {
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let tournament = self.tournament_data(tournament_id).get();
+        require!(tournament.status == TournamentStatus::Active, "Tournament not active");
+        
+        let matches = self.tournament_matches(tournament_id);
+        require!(match_id >= 1 && match_id <= matches.len(), "Match not found");
+        
+        let bracket_match = matches.get(match_id);
+        require!(bracket_match.round == tournament.current_round, "Match not in current round");
+        require!(bracket_match.winner.is_none(), "Match already decided");
+        
+        let player_two = match bracket_match.player_two {
+            Some(player_two) => player_two,
+            None => sc_panic!("Match has no opponent"),
+        };
+        let caller = self.blockchain().get_caller();
+        let opponent = if caller == bracket_match.player_one {
+            player_two
+        } else if caller == player_two {
+            bracket_match.player_one
+        } else {
+            sc_panic!("Not a match player")
+        };
+        
+        let match_battle_mapper = self.tournament_match_battle(tournament_id, match_id);
+        if !match_battle_mapper.is_empty() {
+            let previous_battle = self.battle_data(match_battle_mapper.get()).get();
+            require!(previous_battle.status == BattleStatus::Cancelled, "Match battle already started");
+        }
+        
+        let battle_id = self.start_battle(&caller, opponent, BattleType::Tournament, commitment.into_option());
+        match_battle_mapper.set(battle_id);
+        battle_id
+    }
+    
+    /// Records the winner of a bracket match.
+    /// With a completed tournament battle between both players anyone can report the result,
+    /// otherwise only the organizer or an admin can decide the match.
+    #[endpoint]
+    fn report_match_result(
+        &self,
+        tournament_id: u64,
+        match_id: usize,
+        winner: ManagedAddress,
+        opt_battle_id: OptionalValue<u64>,
+    ) {
+        require!(self.tournaments().contains(&tournament_id), "Tournament not found");
+        
+        let tournament = self.tournament_data(tournament_id).get();
+        require!(tournament.status == TournamentStatus::Active, "Tournament not active");
+        
+        let matches = self.tournament_matches(tournament_id);
+        require!(match_id >= 1 && match_id <= matches.len(), "Match not found");
+        
+        let mut bracket_match = matches.get(match_id);
+        require!(bracket_match.round == tournament.current_round, "Match not in current round");
+        require!(bracket_match.winner.is_none(), "Match already decided");
+        
+        let opponent = match &bracket_match.player_two {
+            Some(player_two) => player_two.clone(),
+            None => sc_panic!("Match has no opponent"),
+        };
+        require!(winner == bracket_match.player_one || winner == opponent, "Winner not in match");
+        
+        match opt_battle_id {
+            OptionalValue::Some(battle_id) => {
+                self.require_valid_match_battle(&tournament, match_id, &bracket_match, &opponent, battle_id, &winner);
+                self.used_tournament_battles().insert(battle_id);
+                bracket_match.battle_id = Some(battle_id);
+            },
+            OptionalValue::None => {
+                self.require_organizer_or_operator(&tournament);
+            },
+        }
+        
+        bracket_match.winner = Some(winner.clone());
+        self.tournament_matches(tournament_id).set(match_id, &bracket_match);
+        
+        self.tournament_match_reported_event(tournament_id, match_id, &winner, bracket_match.battle_id.unwrap_or(0u64));
+        
+        self.advance_tournament_if_round_complete(tournament_id);
+    }
+    
+    fn require_organizer_or_operator(&self, tournament: &Tournament<Self::Api>) {
+        let caller = self.blockchain().get_caller();
+        require!(
+            caller == tournament.organizer || self.has_role(&Role::TournamentOperator, &caller),
+            "Only organizer or tournament operator can call this function"
+        );
+    }
+    
+    fn require_valid_match_battle(
+        &self,
+        tournament: &Tournament<Self::Api>,
+        match_id: usize,
+        bracket_match: &TournamentMatch<Self::Api>,
+        opponent: &ManagedAddress,
+        battle_id: u64,
+        winner: &ManagedAddress,
+    ) {
+        require!(self.battles().contains(&battle_id), "Battle not found");
+        require!(!self.used_tournament_battles().contains(&battle_id), "Battle already used");
+        require!(self.tournament_match_battle(tournament.id, match_id).get() == battle_id, "Battle not started from this match");
+        
+        let battle = self.battle_data(battle_id).get();
+        require!(battle.battle_type == BattleType::Tournament, "Not a tournament battle");
+        require!(battle.status == BattleStatus::Completed, "Battle not completed");
+        require!(battle.created_at >= tournament.start_time, "Battle predates tournament");
+        
+        let same_players = (battle.attacker == bracket_match.player_one && battle.defender == *opponent)
+            || (battle.attacker == *opponent && battle.defender == bracket_match.player_one);
+        require!(same_players, "Battle players do not match");
+        require!(battle.winner.as_ref() == Some(winner), "Battle winner mismatch");
+    }
+    
+    fn create_bracket_round(
+        &self,
+        tournament_id: u64,
+        round: u32,
+        round_slots: &ManagedVec<Option<ManagedAddress>>,
+    ) {
+        // Slots are listed pairwise in bracket order, an empty slot is a bye for the other one.
+        // The better seed comes first in the first round, so player one is never a bye.
+        let match_count = round_slots.len() / 2;
+        for match_index in 0..match_count {
+            let player_one = match round_slots.get(match_index * 2).clone() {
+                Some(player) => player,
+                None => sc_panic!("Empty bracket slot"),
+            };
+            let player_two = round_slots.get(match_index * 2 + 1).clone();
+            let winner = if player_two.is_none() { Some(player_one.clone()) } else { None };
+            
+            self.tournament_matches(tournament_id).push(&TournamentMatch {
+                round,
+                player_one,
+                player_two,
+                winner,
+                battle_id: None,
+            });
+        }
+        
+        self.tournament_data(tournament_id).update(|tournament| {
+            tournament.current_round = round;
+        });
+        self.tournament_round_started_event(tournament_id, round, match_count);
+        
+        // A round made only of byes is decided right away
+        self.advance_tournament_if_round_complete(tournament_id);
+    }
+    
+    fn advance_tournament_if_round_complete(&self, tournament_id: u64) {
+        let tournament = self.tournament_data(tournament_id).get();
+        
+        // Winners of neighbouring matches meet in the next round
+        let mut round_winners = ManagedVec::new();
+        for bracket_match in self.tournament_matches(tournament_id).iter() {
+            if bracket_match.round != tournament.current_round {
+                continue;
+            }
+            match bracket_match.winner {
+                Some(winner) => round_winners.push(Some(winner)),
+                None => return,
+            }
+        }
+        
+        if round_winners.len() == 1 {
+            match round_winners.get(0).clone() {
+                Some(champion) => self.complete_tournament(tournament_id, champion),
+                None => sc_panic!("Empty bracket slot"),
+            }
+        } else {
+            self.create_bracket_round(tournament_id, tournament.current_round + 1, &round_winners);
+        }
+    }
+    
+    fn complete_tournament(&self, tournament_id: u64, champion: ManagedAddress) {
+        let mut tournament = self.tournament_data(tournament_id).get();
+        tournament.status = TournamentStatus::Completed;
+        tournament.winner = Some(champion.clone());
+        self.tournament_data(tournament_id).set(&tournament);
+        
+        self.tournament_completed_event(tournament_id, &champion);
+        
+        self.distribute_prize_pool(&tournament, &champion);
+    }
+    
+    /// Takes the platform and organizer fees off the prize pool, then pays each placement tier
+    /// its share of the rest, split evenly inside the tier.
+    /// Tier 0 is the champion, tier 1 the runner-up, tier 2 the semi-finalists and so on.
+    /// Shares of tiers without players and rounding dust go to the champion.
+    /// The whole pool leaves escrow, the platform fee is kept as treasury revenue.
+    fn distribute_prize_pool(&self, tournament: &Tournament<Self::Api>, champion: &ManagedAddress) {
+        let egld = EgldOrEsdtTokenIdentifier::egld();
+        self.release_escrow(&egld, &tournament.prize_pool);
+        
+        let platform_fee = &tournament.prize_pool * tournament.platform_fee_bps / TOTAL_BASIS_POINTS;
+        let organizer_fee = &tournament.prize_pool * tournament.organizer_fee_bps / TOTAL_BASIS_POINTS;
+        self.record_revenue(RevenueSource::TournamentFee, &egld, &platform_fee);
+        if organizer_fee > 0u32 {
+            self.send().direct_egld(&tournament.organizer, &organizer_fee);
+        }
+        self.tournament_fees_collected_event(tournament.id, &platform_fee, &organizer_fee);
+        
+        let distributable = &tournament.prize_pool - &platform_fee - &organizer_fee;
+        let placement_tiers = self.get_placement_tiers(tournament.id, tournament.current_round);
+        let mut remaining = distributable.clone();
+        
+        for (tier, share_bps) in tournament.prize_shares.iter().enumerate() {
+            if tier == 0 || tier >= placement_tiers.len() {
+                continue;
+            }
+            let tier_players = placement_tiers.get(tier);
+            if tier_players.is_empty() {
+                continue;
+            }
+            
+            let tier_amount = &distributable * share_bps / TOTAL_BASIS_POINTS;
+            let player_amount = tier_amount / tier_players.len() as u64;
+            for player in tier_players.iter() {
+                self.pay_tournament_prize(tournament.id, &player, tier as u32, &player_amount);
+                remaining -= &player_amount;
+            }
+        }
+        
+        self.pay_tournament_prize(tournament.id, champion, 0u32, &remaining);
+    }
+    
+    fn get_placement_tiers(&self, tournament_id: u64, final_round: u32) -> ManagedVec<ManagedVec<ManagedAddress>> {
+        let mut tiers = ManagedVec::new();
+        for _ in 0..=final_round {
+            tiers.push(ManagedVec::new());
+        }
+        
+        // Losers of the final are tier 1, losers of the semi-finals tier 2, ...
+        for bracket_match in self.tournament_matches(tournament_id).iter() {
+            if let (Some(player_two), Some(winner)) = (bracket_match.player_two, bracket_match.winner) {
+                let loser = if winner == bracket_match.player_one { player_two } else { bracket_match.player_one };
+                let tier = (final_round - bracket_match.round + 1) as usize;
+                let mut tier_players = tiers.get(tier).clone();
+                tier_players.push(loser);
+                let _ = tiers.set(tier, tier_players);
+            }
+        }
+        
+        tiers
+    }
+    
+    fn pay_tournament_prize(&self, tournament_id: u64, player: &ManagedAddress, placement_tier: u32, amount: &BigUint) {
+        if *amount == 0u32 {
+            return;
+        }
+        
+        self.send().direct_egld(player, amount);
+        self.tournament_prize_paid_event(tournament_id, player, placement_tier, amount);
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    #[view]
+    fn get_tournament(&self, tournament_id: u64) -> OptionalValue<Tournament<Self::Api>> {
+        if !self.tournaments().contains(&tournament_id) {
+            OptionalValue::None
+        } else {
+            OptionalValue::Some(self.tournament_data(tournament_id).get())
+        }
+    }
+    
+    #[view]
+    fn get_tournament_bracket(&self, tournament_id: u64) -> MultiValueEncoded<TournamentMatch<Self::Api>> {
+        let mut bracket = MultiValueEncoded::new();
+        for bracket_match in self.tournament_matches(tournament_id).iter() {
+            bracket.push(bracket_match);
+        }
+        bracket
+    }
+    
+    #[view]
+    fn is_tournament_cancellable(&self, tournament_id: u64) -> bool {
+        self.tournaments().contains(&tournament_id)
+            && self.is_cancellation_eligible(&self.tournament_data(tournament_id).get())
+    }
+    
+    #[view]
+    fn get_tournament_min_participants(&self) -> u32 {
+        self.get_min_participants()
+    }
+    
+    #[view]
+    fn get_tournament_platform_fee(&self) -> u64 {
+        self.tournament_platform_fee_bps().get()
+    }
+    
+    #[view]
+    fn get_total_tournaments(&self) -> usize {
+        self.tournaments().len()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("tournaments")]
+    fn tournaments(&self) -> UnorderedSetMapper<u64>;
+    
+    #[storage_mapper("tournamentData")]
+    fn tournament_data(&self, tournament_id: u64) -> SingleValueMapper<Tournament<Self::Api>>;
+    
+    #[storage_mapper("nextTournamentId")]
+    fn next_tournament_id(&self) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("tournamentMatches")]
+    fn tournament_matches(&self, tournament_id: u64) -> VecMapper<TournamentMatch<Self::Api>>;
+    
+    /// Latest battle started from a bracket match with `initiate_tournament_battle`.
+    #[storage_mapper("tournamentMatchBattle")]
+    fn tournament_match_battle(&self, tournament_id: u64, match_id: usize) -> SingleValueMapper<u64>;
+    
+    #[storage_mapper("usedTournamentBattles")]
+    fn used_tournament_battles(&self) -> UnorderedSetMapper<u64>;
+    
+    #[storage_mapper("tournamentEntryPaid")]
+    fn tournament_entry_paid(&self, tournament_id: u64, player: &ManagedAddress) -> SingleValueMapper<BigUint>;
+    
+    /// Players who paid for a seed reroll, the only ones a reroll can swap seeds with
+    #[storage_mapper("tournamentRerolledPlayers")]
+    fn tournament_rerolled_players(&self, tournament_id: u64) -> UnorderedSetMapper<ManagedAddress>;
+    
+    #[storage_mapper("tournamentMinParticipants")]
+    fn tournament_min_participants(&self) -> SingleValueMapper<u32>;
+    
+    #[storage_mapper("tournamentPlatformFeeBps")]
+    fn tournament_platform_fee_bps(&self) -> SingleValueMapper<u64>;
+    
+    // ===== EVENTS =====
+    
+    #[event("tournamentCreated")]
+    fn tournament_created_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] name: &ManagedBuffer,
+        prize_pool: &BigUint,
+    );
+    
+    #[event("tournamentJoined")]
+    fn tournament_joined_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] tournament_id: u64,
+        entry_fee: &BigUint,
+    );
+    
+    #[event("tournamentLeft")]
+    fn tournament_left_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] tournament_id: u64,
+        refund: &BigUint,
+    );
+    
+    #[event("tournamentRefunded")]
+    fn tournament_refunded_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] tournament_id: u64,
+        amount: &BigUint,
+    );
+    
+    #[event("tournamentSeedRerolled")]
+    fn tournament_seed_rerolled_event(
+        &self,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] tournament_id: u64,
+        new_seed: u32,
+    );
+    
+    #[event("tournamentCancelled")]
+    fn tournament_cancelled_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        refunded_participants: u32,
+    );
+    
+    #[event("tournamentMinParticipantsSet")]
+    fn tournament_min_participants_set_event(&self, min_participants: u32);
+    
+    #[event("tournamentPlatformFeeSet")]
+    fn tournament_platform_fee_set_event(&self, platform_fee_bps: u64);
+    
+    #[event("tournamentFeesCollected")]
+    fn tournament_fees_collected_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] platform_fee: &BigUint,
+        organizer_fee: &BigUint,
+    );
+    
+    #[event("tournamentStarted")]
+    fn tournament_started_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        participants: u32,
+    );
+    
+    #[event("tournamentRoundStarted")]
+    fn tournament_round_started_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] round: u32,
+        match_count: usize,
+    );
+    
+    #[event("tournamentMatchReported")]
+    fn tournament_match_reported_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] match_id: usize,
+        #[indexed] winner: &ManagedAddress,
+        battle_id: u64,
+    );
+    
+    #[event("tournamentCompleted")]
+    fn tournament_completed_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        champion: &ManagedAddress,
+    );
+    
+    #[event("tournamentPrizePaid")]
+    fn tournament_prize_paid_event(
+        &self,
+        #[indexed] tournament_id: u64,
+        #[indexed] player: &ManagedAddress,
+        #[indexed] placement_tier: u32,
+        amount: &BigUint,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct Tournament<M: ManagedTypeApi> {
+    pub id: u64,
+    pub name: ManagedBuffer<M>,
+    pub organizer: ManagedAddress<M>,
+    pub entry_fee: BigUint<M>,
+    pub prize_pool: BigUint<M>,
+    pub seed_prize_pool: BigUint<M>,
+    pub max_participants: u32,
+    pub current_participants: u32,
+    pub start_time: u64,
+    pub status: TournamentStatus,
+    pub participants: ManagedVec<M, ManagedAddress<M>>,
+    pub current_round: u32,
+    pub winner: Option<ManagedAddress<M>>,
+    pub prize_shares: ManagedVec<M, u64>,
+    pub organizer_fee_bps: u64,
+    pub platform_fee_bps: u64,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct TournamentMatch<M: ManagedTypeApi> {
+    pub round: u32,
+    pub player_one: ManagedAddress<M>,
+    pub player_two: Option<ManagedAddress<M>>,
+    pub winner: Option<ManagedAddress<M>>,
+    pub battle_id: Option<u64>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum TournamentStatus {
+    Registration,
+    Active,
+    Completed,
+    Cancelled,
+}
@@ -0,0 +1,147 @@
+use multiversx_sc::imports::*;
+use multiversx_sc::derive_imports::*;
+
+use crate::access_control::{self, Role};
+
+/// Treasury Module for StardustEngine
+/// Keeps platform revenue apart from escrowed player funds and handles revenue withdrawals
+#[multiversx_sc::module]
+pub trait Treasury: access_control::AccessControl {
+    
+    /// Withdraws collected platform revenue to the calling treasurer.
+    /// Escrowed funds are never withdrawable, only revenue that has been recorded.
+    #[endpoint]
+    fn withdraw_revenue(&self, token_id: EgldOrEsdtTokenIdentifier, amount: BigUint) {
+        self.require_caller_has_role(Role::Treasurer);
+        require!(amount > 0u32, "Amount must be positive");
+        
+        let available = self.available_revenue(&token_id).get();
+        require!(amount <= available, "Insufficient revenue");
+        
+        let balance = self.blockchain().get_sc_balance(&token_id, 0);
+        let escrowed = self.escrowed_balance(&token_id).get();
+        require!(balance >= escrowed + &amount, "Withdrawal would touch escrowed funds");
+        
+        self.available_revenue(&token_id).set(available - &amount);
+        
+        let caller = self.blockchain().get_caller();
+        self.send().direct(&caller, &token_id, 0, &amount);
+        
+        self.revenue_withdrawn_event(&caller, &token_id, &amount);
+    }
+    
+    fn record_revenue(&self, source: RevenueSource, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        if *amount == 0u32 {
+            return;
+        }
+        
+        self.revenue_tokens().insert(token_id.clone());
+        self.collected_revenue(&source, token_id).update(|collected| *collected += amount);
+        self.available_revenue(token_id).update(|available| *available += amount);
+        
+        self.revenue_recorded_event(&source, token_id, amount);
+    }
+    
+    fn add_escrow(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.escrowed_balance(token_id).update(|escrowed| *escrowed += amount);
+    }
+    
+    fn release_escrow(&self, token_id: &EgldOrEsdtTokenIdentifier, amount: &BigUint) {
+        self.escrowed_balance(token_id).update(|escrowed| *escrowed -= amount);
+    }
+    
+    // ===== VIEW FUNCTIONS =====
+    
+    /// Total revenue collected per source and token since deployment, withdrawals included.
+    #[view]
+    fn get_revenue_report(&self) -> MultiValueEncoded<RevenueEntry<Self::Api>> {
+        let mut report = MultiValueEncoded::new();
+        for token_id in self.revenue_tokens().iter() {
+            for source in RevenueSource::ALL.iter() {
+                report.push(RevenueEntry {
+                    source: source.clone(),
+                    token_id: token_id.clone(),
+                    collected: self.collected_revenue(source, &token_id).get(),
+                });
+            }
+        }
+        report
+    }
+    
+    #[view]
+    fn get_revenue_by_source(&self, source: RevenueSource, token_id: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.collected_revenue(&source, &token_id).get()
+    }
+    
+    #[view]
+    fn get_available_revenue(&self, token_id: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.available_revenue(&token_id).get()
+    }
+    
+    #[view]
+    fn get_escrowed_balance(&self, token_id: EgldOrEsdtTokenIdentifier) -> BigUint {
+        self.escrowed_balance(&token_id).get()
+    }
+    
+    // ===== STORAGE =====
+    
+    #[storage_mapper("revenueTokens")]
+    fn revenue_tokens(&self) -> UnorderedSetMapper<EgldOrEsdtTokenIdentifier>;
+    
+    #[storage_mapper("collectedRevenue")]
+    fn collected_revenue(&self, source: &RevenueSource, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+    
+    #[storage_mapper("availableRevenue")]
+    fn available_revenue(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+    
+    #[storage_mapper("escrowedBalance")]
+    fn escrowed_balance(&self, token_id: &EgldOrEsdtTokenIdentifier) -> SingleValueMapper<BigUint>;
+    
+    // ===== EVENTS =====
+    
+    #[event("revenueRecorded")]
+    fn revenue_recorded_event(
+        &self,
+        #[indexed] source: &RevenueSource,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+    
+    #[event("revenueWithdrawn")]
+    fn revenue_withdrawn_event(
+        &self,
+        #[indexed] recipient: &ManagedAddress,
+        #[indexed] token_id: &EgldOrEsdtTokenIdentifier,
+        amount: &BigUint,
+    );
+}
+
+// ===== DATA STRUCTURES =====
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone)]
+pub struct RevenueEntry<M: ManagedTypeApi> {
+    pub source: RevenueSource,
+    pub token_id: EgldOrEsdtTokenIdentifier<M>,
+    pub collected: BigUint<M>,
+}
+
+#[type_abi]
+#[derive(TopEncode, TopDecode, NestedEncode, NestedDecode, Clone, PartialEq, Eq)]
+pub enum RevenueSource {
+    Mint,
+    TournamentFee,
+    MarketplaceFee,
+    BattleRake,
+    AssetUpgrade,
+}
+
+impl RevenueSource {
+    pub const ALL: [RevenueSource; 5] = [
+        RevenueSource::Mint,
+        RevenueSource::TournamentFee,
+        RevenueSource::MarketplaceFee,
+        RevenueSource::BattleRake,
+        RevenueSource::AssetUpgrade,
+    ];
+}
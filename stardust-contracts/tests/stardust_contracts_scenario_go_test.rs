@@ -8,3 +8,128 @@ fn world() -> ScenarioWorld {
 fn empty_go() {
     world().run("scenarios/stardust_contracts.scen.json");
 }
+
+#[test]
+fn tournament_lifecycle_go() {
+    world().run("scenarios/tournament_lifecycle.scen.json");
+}
+
+#[test]
+fn tournament_seeding_go() {
+    world().run("scenarios/tournament_seeding.scen.json");
+}
+
+#[test]
+fn tournament_cancellation_go() {
+    world().run("scenarios/tournament_cancellation.scen.json");
+}
+
+#[test]
+fn tournament_default_shares_go() {
+    world().run("scenarios/tournament_default_shares.scen.json");
+}
+
+#[test]
+fn game_assets_go() {
+    world().run("scenarios/game_assets.scen.json");
+}
+
+#[test]
+fn access_control_go() {
+    world().run("scenarios/access_control.scen.json");
+}
+
+#[test]
+fn pause_go() {
+    world().run("scenarios/pause.scen.json");
+}
+
+#[test]
+fn battle_pause_go() {
+    world().run("scenarios/battle_pause.scen.json");
+}
+
+#[test]
+fn combat_go() {
+    world().run("scenarios/combat.scen.json");
+}
+
+#[test]
+fn combat_randomness_go() {
+    world().run("scenarios/combat_randomness.scen.json");
+}
+
+#[test]
+fn battle_timeouts_go() {
+    world().run("scenarios/battle_timeouts.scen.json");
+}
+
+#[test]
+fn battle_wagers_go() {
+    world().run("scenarios/battle_wagers.scen.json");
+}
+
+#[test]
+fn ranked_matchmaking_go() {
+    world().run("scenarios/ranked_matchmaking.scen.json");
+}
+
+#[test]
+fn seasons_go() {
+    world().run("scenarios/seasons.scen.json");
+}
+
+#[test]
+fn asset_locks_go() {
+    world().run("scenarios/asset_locks.scen.json");
+}
+
+#[test]
+fn battle_limits_go() {
+    world().run("scenarios/battle_limits.scen.json");
+}
+
+#[test]
+fn abandoned_challenge_go() {
+    world().run("scenarios/abandoned_challenge.scen.json");
+}
+
+#[test]
+fn asset_classes_go() {
+    world().run("scenarios/asset_classes.scen.json");
+}
+
+#[test]
+fn asset_evolution_go() {
+    world().run("scenarios/asset_evolution.scen.json");
+}
+
+#[test]
+fn level_curves_go() {
+    world().run("scenarios/level_curves.scen.json");
+}
+
+#[test]
+fn level_rewards_go() {
+    world().run("scenarios/level_rewards.scen.json");
+}
+
+#[test]
+fn stardust_token_go() {
+    world().run("scenarios/stardust_token.scen.json");
+}
+
+#[test]
+fn token_issue_pending_go() {
+    world().run("scenarios/token_issue_pending.scen.json");
+}
+
+#[test]
+fn stardust_sinks_go() {
+    world().run("scenarios/stardust_sinks.scen.json");
+}
+
+#[test]
+fn marketplace_go() {
+    world().run("scenarios/marketplace.scen.json");
+}
@@ -12,3 +12,128 @@ fn world() -> ScenarioWorld {
 fn empty_rs() {
     world().run("scenarios/stardust_contracts.scen.json");
 }
+
+#[test]
+fn tournament_lifecycle_rs() {
+    world().run("scenarios/tournament_lifecycle.scen.json");
+}
+
+#[test]
+fn tournament_seeding_rs() {
+    world().run("scenarios/tournament_seeding.scen.json");
+}
+
+#[test]
+fn tournament_cancellation_rs() {
+    world().run("scenarios/tournament_cancellation.scen.json");
+}
+
+#[test]
+fn tournament_default_shares_rs() {
+    world().run("scenarios/tournament_default_shares.scen.json");
+}
+
+#[test]
+fn game_assets_rs() {
+    world().run("scenarios/game_assets.scen.json");
+}
+
+#[test]
+fn access_control_rs() {
+    world().run("scenarios/access_control.scen.json");
+}
+
+#[test]
+fn pause_rs() {
+    world().run("scenarios/pause.scen.json");
+}
+
+#[test]
+fn battle_pause_rs() {
+    world().run("scenarios/battle_pause.scen.json");
+}
+
+#[test]
+fn combat_rs() {
+    world().run("scenarios/combat.scen.json");
+}
+
+#[test]
+fn combat_randomness_rs() {
+    world().run("scenarios/combat_randomness.scen.json");
+}
+
+#[test]
+fn battle_timeouts_rs() {
+    world().run("scenarios/battle_timeouts.scen.json");
+}
+
+#[test]
+fn battle_wagers_rs() {
+    world().run("scenarios/battle_wagers.scen.json");
+}
+
+#[test]
+fn ranked_matchmaking_rs() {
+    world().run("scenarios/ranked_matchmaking.scen.json");
+}
+
+#[test]
+fn seasons_rs() {
+    world().run("scenarios/seasons.scen.json");
+}
+
+#[test]
+fn asset_locks_rs() {
+    world().run("scenarios/asset_locks.scen.json");
+}
+
+#[test]
+fn battle_limits_rs() {
+    world().run("scenarios/battle_limits.scen.json");
+}
+
+#[test]
+fn abandoned_challenge_rs() {
+    world().run("scenarios/abandoned_challenge.scen.json");
+}
+
+#[test]
+fn asset_classes_rs() {
+    world().run("scenarios/asset_classes.scen.json");
+}
+
+#[test]
+fn asset_evolution_rs() {
+    world().run("scenarios/asset_evolution.scen.json");
+}
+
+#[test]
+fn level_curves_rs() {
+    world().run("scenarios/level_curves.scen.json");
+}
+
+#[test]
+fn level_rewards_rs() {
+    world().run("scenarios/level_rewards.scen.json");
+}
+
+#[test]
+fn stardust_token_rs() {
+    world().run("scenarios/stardust_token.scen.json");
+}
+
+#[test]
+fn token_issue_pending_rs() {
+    world().run("scenarios/token_issue_pending.scen.json");
+}
+
+#[test]
+fn stardust_sinks_rs() {
+    world().run("scenarios/stardust_sinks.scen.json");
+}
+
+#[test]
+fn marketplace_rs() {
+    world().run("scenarios/marketplace.scen.json");
+}